@@ -0,0 +1,103 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// A malformed puzzle input, located by the line and column of the offending text.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    day: u8,
+    line: usize,
+    column: usize,
+    text: String,
+    message: String,
+}
+
+impl ParseError {
+    /// Creates an error for `span`, which must be a slice of `input`, so its position can be
+    /// recovered from where it starts in the input.
+    pub fn new(day: u8, input: &str, span: &str, message: impl Into<String>) -> Self {
+        let offset = (span.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        debug_assert!(offset <= input.len(), "span is not part of the input");
+        let before = input.get(..offset).unwrap_or_default();
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError {
+            day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: span.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " at {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `span`, a slice of `input`, reporting where it is on failure.
+pub fn parse_field<T>(day: u8, input: &str, span: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    span.parse()
+        .map_err(|e| ParseError::new(day, input, span, format!("{e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_field, ParseError};
+
+    #[test]
+    fn test_locates_span() {
+        let input = "1-3,2-4\n5-x,6-7\n";
+        let span = &input[10..11];
+        let error = ParseError::new(4, input, span, "expected a number");
+
+        assert_eq!(4, error.day());
+        assert_eq!(2, error.line());
+        assert_eq!(3, error.column());
+        assert_eq!("x", error.text());
+        assert_eq!(
+            "day 4, line 2, column 3: expected a number at \"x\"",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_field() {
+        let input = "12 ab";
+        assert_eq!(Ok(12), parse_field::<u32>(1, input, &input[..2]));
+
+        let error = parse_field::<u32>(1, input, &input[3..]).unwrap_err();
+        assert_eq!((1, 4), (error.line(), error.column()));
+        assert_eq!("ab", error.text());
+    }
+}
@@ -0,0 +1,294 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Compass, Direction, ParseError, Point};
+
+/// A rectangular grid stored row by row, addressed by `(x, y)` with the origin top left.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per non-empty line, converting every character with `f`. Rows of
+    /// differing lengths and characters rejected by `f` are reported where they occur.
+    pub fn parse<E: fmt::Display>(
+        day: u8,
+        input: &str,
+        mut f: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in crate::lines(input) {
+            let before = cells.len();
+            for (i, c) in line.char_indices() {
+                let cell = f(c).map_err(|e| {
+                    ParseError::new(day, input, &line[i..i + c.len_utf8()], e.to_string())
+                })?;
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - before;
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(ParseError::new(
+                    day,
+                    input,
+                    line,
+                    format!("expected {} columns, found {row_width}", width.unwrap()),
+                ));
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // `chunks` panics on a zero size, which an empty grid would otherwise pass
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn positions(&self) -> impl DoubleEndedIterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// The index of `point` in this grid, if it lies inside it.
+    pub fn index_of(&self, point: Point) -> Option<(usize, usize)> {
+        point.to_index(self.width, self.height)
+    }
+
+    pub fn at(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self[index])
+    }
+
+    /// The position `offset` away from `(x, y)`, if it is still inside the grid.
+    pub fn step(&self, x: usize, y: usize, offset: Point) -> Option<(usize, usize)> {
+        self.index_of(Point::from((x, y)) + offset)
+    }
+
+    /// The cells seen when walking from `(x, y)` towards the edge, not including `(x, y)`.
+    pub fn ray(&self, x: usize, y: usize, direction: Direction) -> impl Iterator<Item = &T> {
+        let offset = direction.offset();
+        std::iter::successors(self.step(x, y, offset), move |&(x, y)| {
+            self.step(x, y, offset)
+        })
+        .map(|(x, y)| &self[(x, y)])
+    }
+
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(x, y, d.offset()))
+    }
+
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Compass::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(x, y, d.offset()))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Draws the grid one character per cell, one line per row.
+    pub fn render(&self, mut glyph: impl FnMut((usize, usize), &T) -> char) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for (position, cell) in self.iter() {
+            if position.0 == 0 && position.1 > 0 {
+                out.push('\n');
+            }
+            out.push(glyph(position, cell));
+        }
+        out
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside the grid"))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.at(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let index = self
+            .index_of(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"));
+        &mut self[index]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::{Direction, Point};
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(0, input, |c| c.to_digit(10).ok_or("expected a digit")).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n");
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&6), grid.get(2, 1));
+        assert_eq!(None, grid.get(3, 1));
+        assert_eq!("123\n456", grid.to_string());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error =
+            Grid::parse(0, "12\n3x", |c| c.to_digit(10).ok_or("expected a digit")).unwrap_err();
+        assert_eq!((2, 2), (error.line(), error.column()));
+
+        let error =
+            Grid::parse(0, "12\n345", |c| c.to_digit(10).ok_or("expected a digit")).unwrap_err();
+        assert_eq!((2, 1), (error.line(), error.column()));
+    }
+
+    #[test]
+    fn test_lines_through_grid() {
+        let grid = digits("123\n456\n789");
+        assert_eq!(vec![&2, &5, &8], grid.column(1).collect::<Vec<_>>());
+        assert_eq!(&[4, 5, 6], grid.row(1));
+        assert_eq!(
+            vec![&4],
+            grid.ray(1, 1, Direction::Left).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![&6, &3],
+            grid.ray(2, 2, Direction::Up).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789");
+        assert_eq!(4, grid.neighbours4(1, 1).count());
+        assert_eq!(8, grid.neighbours8(1, 1).count());
+        let mut corner: Vec<_> = grid.neighbours8(0, 0).collect();
+        corner.sort();
+        assert_eq!(vec![(0, 1), (1, 0), (1, 1)], corner);
+        assert_eq!(Some(&9), grid.at(Point::new(2, 2)));
+        assert_eq!(None, grid.at(Point::new(-1, 2)));
+    }
+}
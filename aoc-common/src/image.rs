@@ -0,0 +1,80 @@
+//! Turns grids into pictures, either for the terminal or as plain PPM/PGM images that most
+//! image viewers open.
+
+use std::fmt::Write;
+
+use crate::Grid;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    /// The perceived brightness, for greyscale output.
+    pub fn luma(self) -> u8 {
+        let Rgb(r, g, b) = self;
+        ((299 * u32::from(r) + 587 * u32::from(g) + 114 * u32::from(b)) / 1000) as u8
+    }
+}
+
+/// Draws one character per cell in its colour, using 24-bit ANSI escape codes.
+pub fn to_ansi<T>(grid: &Grid<T>, mut cell: impl FnMut(&T) -> (char, Rgb)) -> String {
+    let mut out = String::new();
+    for row in grid.rows() {
+        let mut current = None;
+        for (glyph, colour) in row.iter().map(&mut cell) {
+            if current != Some(colour) {
+                let Rgb(r, g, b) = colour;
+                write!(out, "\x1b[38;2;{r};{g};{b}m").expect("writing to a String never fails");
+                current = Some(colour);
+            }
+            out.push(glyph);
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+/// A binary (P6) PPM image with one pixel per cell.
+pub fn to_ppm<T>(grid: &Grid<T>, mut colour: impl FnMut(&T) -> Rgb) -> Vec<u8> {
+    let mut out = format!("P6\n{} {}\n255\n", grid.width(), grid.height()).into_bytes();
+    for (_, cell) in grid.iter() {
+        let Rgb(r, g, b) = colour(cell);
+        out.extend([r, g, b]);
+    }
+    out
+}
+
+/// A binary (P5) PGM image with one greyscale pixel per cell.
+pub fn to_pgm<T>(grid: &Grid<T>, mut colour: impl FnMut(&T) -> Rgb) -> Vec<u8> {
+    let mut out = format!("P5\n{} {}\n255\n", grid.width(), grid.height()).into_bytes();
+    out.extend(grid.iter().map(|(_, cell)| colour(cell).luma()));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_ansi, to_pgm, to_ppm, Rgb};
+    use crate::Grid;
+
+    #[test]
+    fn test_images() {
+        let grid = Grid::from_fn(2, 1, |x, _| x == 1);
+        let colour = |&lit: &bool| {
+            if lit {
+                Rgb(255, 255, 255)
+            } else {
+                Rgb(0, 0, 0)
+            }
+        };
+
+        assert_eq!(
+            b"P6\n2 1\n255\n\0\0\0\xff\xff\xff",
+            &to_ppm(&grid, colour)[..]
+        );
+        assert_eq!(b"P5\n2 1\n255\n\0\xff", &to_pgm(&grid, colour)[..]);
+        assert_eq!(
+            "\x1b[38;2;0;0;0m.\x1b[38;2;255;255;255m#\x1b[0m\n",
+            to_ansi(&grid, |lit| (if *lit { '#' } else { '.' }, colour(lit)))
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use std::fmt;
+
+/// An integer type that intervals can be built from.
+pub trait Integer: Copy + Ord + fmt::Debug {
+    /// The next value up, if there is one.
+    fn successor(self) -> Option<Self>;
+    /// How many values lie between `self` and `other`, not counting `other`.
+    fn distance(self, other: Self) -> u64;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),+) => {
+        $(
+            impl Integer for $t {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn distance(self, other: Self) -> u64 {
+                    self.abs_diff(other) as u64
+                }
+            }
+        )+
+    };
+}
+
+impl_integer!(i32, i64, u32, u64, usize);
+
+/// The integers from `start` up to and including `end`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        assert!(start <= end, "interval {start:?}..={end:?} is empty");
+        Interval { start, end }
+    }
+
+    pub fn start(self) -> T {
+        self.start
+    }
+
+    pub fn end(self) -> T {
+        self.end
+    }
+
+    /// The number of integers in the interval. This is a `u128` because an interval spanning every
+    /// `u64` or `i64` holds 2^64 integers, one more than a `u64` can count.
+    pub fn length(self) -> u128 {
+        u128::from(self.start.distance(self.end)) + 1
+    }
+
+    pub fn contains(self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn contains_interval(self, other: Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(self, other: Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(self, other: Interval<T>) -> Option<Interval<T>> {
+        self.overlaps(other)
+            .then(|| Interval::new(self.start.max(other.start), self.end.min(other.end)))
+    }
+
+    /// The single interval covering both, if they overlap or are directly next to each other.
+    pub fn union(self, other: Interval<T>) -> Option<Interval<T>> {
+        let touches = |a: Interval<T>, b: Interval<T>| a.end.successor() == Some(b.start);
+        (self.overlaps(other) || touches(self, other) || touches(other, self))
+            .then(|| Interval::new(self.start.min(other.start), self.end.max(other.end)))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// A set of integers stored as sorted intervals, merged whenever they overlap or touch.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Adds `interval`, merging it with every interval it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut merged = interval;
+        // Merging only grows `merged` towards intervals further along, so a single pass suffices
+        self.intervals.retain(|&other| match merged.union(other) {
+            Some(union) => {
+                merged = union;
+                false
+            }
+            None => true,
+        });
+        let at = self
+            .intervals
+            .partition_point(|other| other.start < merged.start);
+        self.intervals.insert(at, merged);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of integers in the set, at most 2^64 as the intervals never overlap.
+    pub fn total_length(&self) -> u128 {
+        self.intervals.iter().map(|i| i.length()).sum()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let at = self.intervals.partition_point(|i| i.end < value);
+        self.intervals.get(at).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn overlaps(&self, interval: Interval<T>) -> bool {
+        self.intervals.iter().any(|i| i.overlaps(interval))
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        union.merge(other);
+        union
+    }
+
+    /// Adds every interval of `other` to this set.
+    pub fn merge(&mut self, other: &IntervalSet<T>) {
+        other.iter().for_each(|i| self.insert(i));
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.iter()
+            .flat_map(|a| other.iter().filter_map(move |b| a.intersection(b)))
+            .collect()
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        iter.into_iter().for_each(|i| set.insert(i));
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::{Interval, IntervalSet};
+
+    #[test_case((1, 5), (3, 8), true, Some((3, 5)), Some((1, 8)))]
+    #[test_case((1, 5), (6, 8), false, None, Some((1, 8)))]
+    #[test_case((1, 5), (7, 8), false, None, None)]
+    #[test_case((2, 8), (3, 7), true, Some((3, 7)), Some((2, 8)))]
+    fn test_interval(
+        a: (i64, i64),
+        b: (i64, i64),
+        overlaps: bool,
+        intersection: Option<(i64, i64)>,
+        union: Option<(i64, i64)>,
+    ) {
+        let interval = |(start, end)| Interval::new(start, end);
+        let (a, b) = (interval(a), interval(b));
+        assert_eq!(overlaps, a.overlaps(b));
+        assert_eq!(intersection.map(interval), a.intersection(b));
+        assert_eq!(union.map(interval), a.union(b));
+        assert_eq!(union.map(interval), b.union(a));
+    }
+
+    #[test]
+    fn test_interval_bounds() {
+        let outer = Interval::new(0_u64, u64::MAX);
+        assert_eq!(
+            u128::from(u64::MAX),
+            Interval::new(1_u64, u64::MAX).length()
+        );
+        assert_eq!(1 << 64, outer.length());
+        assert_eq!(1 << 64, Interval::new(i64::MIN, i64::MAX).length());
+        let halves: IntervalSet<i64> = [Interval::new(i64::MIN, -1), Interval::new(1, i64::MAX)]
+            .into_iter()
+            .collect();
+        assert_eq!((1 << 64) - 1, halves.total_length());
+        assert!(outer.contains_interval(Interval::new(5, 6)));
+        assert!(!Interval::new(5, 6).contains_interval(outer));
+        assert!(outer.contains(u64::MAX));
+    }
+
+    #[test]
+    fn test_set_merges() {
+        let set: IntervalSet<i64> = [(12, 12), (-2, 2), (2, 14), (16, 24), (15, 15)]
+            .into_iter()
+            .map(|(start, end)| Interval::new(start, end))
+            .collect();
+        assert_eq!(vec![Interval::new(-2, 24)], set.iter().collect::<Vec<_>>());
+        assert_eq!(27, set.total_length());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: IntervalSet<u32> = [Interval::new(0, 3), Interval::new(8, 9)]
+            .into_iter()
+            .collect();
+        let b: IntervalSet<u32> = [Interval::new(2, 8)].into_iter().collect();
+
+        assert!(a.contains(9) && !a.contains(5));
+        assert!(a.overlaps(Interval::new(4, 8)) && !a.overlaps(Interval::new(4, 7)));
+        assert_eq!(
+            vec![Interval::new(2, 3), Interval::new(8, 8)],
+            a.intersection(&b).iter().collect::<Vec<_>>()
+        );
+        assert_eq!(10, a.union(&b).total_length());
+    }
+}
@@ -0,0 +1,123 @@
+mod error;
+mod grid;
+pub mod image;
+mod interval;
+mod point;
+pub mod search;
+mod solution;
+
+pub use error::{parse_field, ParseError};
+pub use grid::Grid;
+pub use interval::{Integer, Interval, IntervalSet};
+pub use point::{Compass, Direction, Point};
+pub use solution::{Answer, Solution};
+
+/// Iterates the non-empty lines of a puzzle input with surrounding whitespace removed.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(str::trim).filter(|line| !line.is_empty())
+}
+
+/// Iterates the blocks of a puzzle input, separated by lines that are blank or only hold
+/// whitespace, so `\r\n` line endings work too. Blocks are slices of `input`, for locating errors.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input.trim();
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let mut end = 0;
+        for line in rest.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                break;
+            }
+            end += line.len();
+        }
+        let block = rest[..end].trim_end();
+        rest = rest[end..].trim_start();
+        Some(block)
+    })
+}
+
+/// Generates a test per part that solves `samples/<name>.txt` of the calling crate and compares
+/// the answer with `samples/<name>.part1.txt` or `samples/<name>.part2.txt`. Without a list of
+/// samples, both parts of `samples/sample.txt` are checked.
+#[macro_export]
+macro_rules! sample_tests {
+    ($solution:expr) => {
+        $crate::sample_tests!($solution, sample => part1, part2);
+    };
+    ($solution:expr, $($sample:ident => $($part:ident),+);+ $(;)?) => {
+        #[cfg(test)]
+        mod sample_tests {
+            $(
+                mod $sample {
+                    $(
+                        #[test]
+                        fn $part() {
+                            use $crate::{Answer, Solution};
+
+                            let samples = concat!(env!("CARGO_MANIFEST_DIR"), "/samples/");
+                            let input = include_str!(concat!(
+                                env!("CARGO_MANIFEST_DIR"),
+                                "/samples/",
+                                stringify!($sample),
+                                ".txt"
+                            ));
+                            let expected = include_str!(concat!(
+                                env!("CARGO_MANIFEST_DIR"),
+                                "/samples/",
+                                stringify!($sample),
+                                ".",
+                                stringify!($part),
+                                ".txt"
+                            ));
+
+                            let solution = $solution;
+                            let parsed = solution.parse(input).unwrap_or_else(|e| {
+                                panic!("{samples}{}.txt: {e}", stringify!($sample))
+                            });
+                            assert_eq!(
+                                Ok(expected.trim_end().to_owned()),
+                                solution.$part(&parsed).to_answer()
+                            );
+                        }
+                    )+
+                }
+            )+
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{blocks, lines};
+
+    #[test]
+    fn test_lines() {
+        let input = "  A Y
+        B X
+        C Z
+";
+        assert_eq!(vec!["A Y", "B X", "C Z"], lines(input).collect::<Vec<_>>());
+        assert_eq!(
+            vec!["A Y", "B X"],
+            lines("A Y\r\n\r\n  \nB X\r\n").collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "1000\n2000\n\n3000\n\n4000\n";
+        assert_eq!(
+            vec!["1000\n2000", "3000", "4000"],
+            blocks(input).collect::<Vec<_>>()
+        );
+
+        let input = "1000\r\n2000\r\n\r\n3000\r\n \r\n\r\n4000\r\n";
+        assert_eq!(
+            vec!["1000\r\n2000", "3000", "4000"],
+            blocks(input).collect::<Vec<_>>()
+        );
+        assert_eq!(None, blocks("\n \n").next());
+    }
+}
@@ -0,0 +1,220 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// One of the four axis-aligned directions, with y growing downwards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+}
+
+/// The eight compass points, including the diagonals, with y growing downwards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Compass {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Compass {
+    pub const ALL: [Compass; 8] = [
+        Compass::North,
+        Compass::NorthEast,
+        Compass::East,
+        Compass::SouthEast,
+        Compass::South,
+        Compass::SouthWest,
+        Compass::West,
+        Compass::NorthWest,
+    ];
+
+    pub fn offset(self) -> Point {
+        match self {
+            Compass::North => Point::new(0, -1),
+            Compass::NorthEast => Point::new(1, -1),
+            Compass::East => Point::new(1, 0),
+            Compass::SouthEast => Point::new(1, 1),
+            Compass::South => Point::new(0, 1),
+            Compass::SouthWest => Point::new(-1, 1),
+            Compass::West => Point::new(-1, 0),
+            Compass::NorthWest => Point::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Compass::North,
+            Direction::Right => Compass::East,
+            Direction::Down => Compass::South,
+            Direction::Left => Compass::West,
+        }
+    }
+}
+
+/// A signed position or offset on a 2D plane.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Clamps both components to -1, 0 or 1, giving a single step towards the same direction.
+    pub fn signum(self) -> Self {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |d| self + d.offset())
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        Compass::ALL.into_iter().map(move |d| self + d.offset())
+    }
+
+    /// The `(x, y)` index of this point in a grid of the given size, if it lies inside it.
+    pub fn to_index(self, width: usize, height: usize) -> Option<(usize, usize)> {
+        let x = usize::try_from(self.x).ok().filter(|&x| x < width)?;
+        let y = usize::try_from(self.y).ok().filter(|&y| y < height)?;
+        Some((x, y))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point::new(x as i64, y as i64)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Point {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Compass, Direction, Point};
+
+    #[test]
+    fn test_arithmetic() {
+        let p = Point::new(3, -2);
+        assert_eq!(
+            Point::new(4, -4),
+            p + Direction::Up.offset() * 2 + Direction::Right.offset()
+        );
+        assert_eq!(Point::new(-3, 2), -p);
+        assert_eq!(Point::new(1, -1), (p - Point::ORIGIN).signum());
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(8, 7);
+        let b = Point::new(2, 10);
+        assert_eq!(9, a.manhattan(b));
+        assert_eq!(6, a.chebyshev(b));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = Point::new(1, 1);
+        assert!(p.neighbours4().all(|n| n.manhattan(p) == 1));
+        assert!(p.neighbours8().all(|n| n.chebyshev(p) == 1));
+        assert_eq!(8, p.neighbours8().count());
+        assert_eq!(Compass::North, Direction::Up.into());
+    }
+
+    #[test]
+    fn test_to_index() {
+        assert_eq!(Some((2, 0)), Point::new(2, 0).to_index(3, 1));
+        assert_eq!(None, Point::new(3, 0).to_index(3, 1));
+        assert_eq!(None, Point::new(-1, 0).to_index(3, 1));
+    }
+}
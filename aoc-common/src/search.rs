@@ -0,0 +1,368 @@
+//! Shortest path searches over graphs given as a neighbour function, starting from any number
+//! of nodes at once.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The route to a goal, from the start it was reached from up to and including the goal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+/// Every node reached so far, with the node it was reached from and the cost of getting there.
+struct Visited<N, C> {
+    nodes: Vec<(N, Option<usize>, C)>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Visited<N, C> {
+    fn new() -> Self {
+        Visited {
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    /// Records `node` unless it was seen before, returning its index if it is new.
+    fn insert(&mut self, node: N, parent: Option<usize>, cost: C) -> Option<usize> {
+        match self.indices.entry(node) {
+            Entry::Occupied(_) => None,
+            Entry::Vacant(entry) => {
+                let index = self.nodes.len();
+                self.nodes.push((entry.key().clone(), parent, cost));
+                entry.insert(index);
+                Some(index)
+            }
+        }
+    }
+
+    fn path(&self, mut index: usize) -> Path<N, C> {
+        let cost = self.nodes[index].2;
+        let mut nodes = vec![self.nodes[index].0.clone()];
+        while let Some(parent) = self.nodes[index].1 {
+            nodes.push(self.nodes[parent].0.clone());
+            index = parent;
+        }
+        nodes.reverse();
+        Path { nodes, cost }
+    }
+}
+
+/// Finds the path with the fewest steps from any of `starts` to a node accepted by `is_goal`.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new();
+    let mut queue: VecDeque<usize> = starts
+        .into_iter()
+        .filter_map(|start| visited.insert(start, None, 0))
+        .collect();
+
+    while let Some(index) = queue.pop_front() {
+        let (node, _, steps) = visited.nodes[index].clone();
+        if is_goal(&node) {
+            return Some(visited.path(index));
+        }
+        queue.extend(
+            neighbours(&node)
+                .into_iter()
+                .filter_map(|next| visited.insert(next, Some(index), steps + 1)),
+        );
+    }
+
+    None
+}
+
+/// Every node reachable from the starts of a search, with the cheapest cost to get there.
+pub struct Distances<N, C = usize> {
+    visited: Visited<N, C>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Distances<N, C> {
+    pub fn get(&self, node: &N) -> Option<C> {
+        self.visited
+            .indices
+            .get(node)
+            .map(|&index| self.visited.nodes[index].2)
+    }
+
+    /// The cheapest path to `node`, beginning at the start it is closest to.
+    pub fn path_to(&self, node: &N) -> Option<Path<N, C>> {
+        self.visited
+            .indices
+            .get(node)
+            .map(|&index| self.visited.path(index))
+    }
+
+    /// Every reached node in the order it was first reached, which for a breadth-first search
+    /// is by increasing distance.
+    pub fn iter(&self) -> impl Iterator<Item = (&N, C)> {
+        self.visited
+            .nodes
+            .iter()
+            .map(|(node, _, cost)| (node, *cost))
+    }
+}
+
+/// Walks out from all of `starts` at once until every reachable node has been found.
+pub fn bfs_reachable<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Distances<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new();
+    let mut queue: VecDeque<usize> = starts
+        .into_iter()
+        .filter_map(|start| visited.insert(start, None, 0))
+        .collect();
+
+    while let Some(index) = queue.pop_front() {
+        let (node, _, steps) = visited.nodes[index].clone();
+        queue.extend(
+            neighbours(&node)
+                .into_iter()
+                .filter_map(|next| visited.insert(next, Some(index), steps + 1)),
+        );
+    }
+
+    Distances { visited }
+}
+
+/// Finds the cheapest path from any of `starts` to a node accepted by `is_goal`, where
+/// `neighbours` yields every node reachable in one step along with the cost of that step.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but explores the nodes that `heuristic` estimates to be closer to a goal
+/// first. The heuristic must never overestimate the remaining cost, or the path found may not
+/// be the cheapest.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (visited, goal) = explore(starts, neighbours, heuristic, is_goal);
+    goal.map(|goal| visited.path(goal))
+}
+
+/// Like [`bfs_reachable`], but for steps of differing costs.
+pub fn dijkstra_reachable<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+) -> Distances<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (visited, _) = explore(starts, neighbours, |_| C::default(), |_| false);
+    Distances { visited }
+}
+
+/// Visits nodes cheapest estimate first until one is a goal, returning what was visited and
+/// the index of the goal that was found.
+fn explore<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (Visited<N, C>, Option<usize>)
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited = Visited::new();
+    let mut frontier = BinaryHeap::new();
+    for start in starts {
+        if let Some(index) = visited.insert(start.clone(), None, C::default()) {
+            frontier.push(Candidate {
+                estimate: heuristic(&start),
+                cost: C::default(),
+                index,
+            });
+        }
+    }
+
+    while let Some(Candidate { cost, index, .. }) = frontier.pop() {
+        let node = visited.nodes[index].0.clone();
+        // A cheaper way to this node was found after this candidate was queued
+        if cost > visited.nodes[index].2 {
+            continue;
+        }
+        if is_goal(&node) {
+            return (visited, Some(index));
+        }
+
+        for (next, step) in neighbours(&node) {
+            let cost = cost + step;
+            let next_index = match visited.indices.get(&next) {
+                Some(&known) if cost >= visited.nodes[known].2 => continue,
+                Some(&known) => {
+                    visited.nodes[known].1 = Some(index);
+                    visited.nodes[known].2 = cost;
+                    known
+                }
+                None => visited
+                    .insert(next.clone(), Some(index), cost)
+                    .expect("the node was not visited yet"),
+            };
+            frontier.push(Candidate {
+                estimate: cost + heuristic(&next),
+                cost,
+                index: next_index,
+            });
+        }
+    }
+
+    (visited, None)
+}
+
+/// A queued node, ordered so that the max-heap pops the lowest estimate first.
+struct Candidate<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> Ord for Candidate<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            // Among equal estimates, the one furthest along is likely closest to a goal
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for Candidate<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Candidate<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Candidate<C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, bfs_reachable, dijkstra, dijkstra_reachable, Path};
+    use crate::Point;
+
+    // 0 - 1 - 2 - 3, with a costly shortcut 0 - 3
+    fn weighted(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(0, 1), (2, 1)],
+            2 => vec![(1, 1), (3, 1)],
+            3 => vec![(2, 1), (0, 5)],
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_bfs_takes_fewest_steps() {
+        let path = bfs(
+            [0],
+            |&n| weighted(&n).into_iter().map(|(n, _)| n),
+            |&n| n == 3,
+        );
+        assert_eq!(
+            Some(Path {
+                nodes: vec![0, 3],
+                cost: 1
+            }),
+            path
+        );
+    }
+
+    #[test]
+    fn test_bfs_reachable() {
+        let distances = bfs_reachable([1], |&n| weighted(&n).into_iter().map(|(n, _)| n));
+        assert_eq!(
+            vec![(&1, 0), (&0, 1), (&2, 1), (&3, 2)],
+            distances.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![1, 0, 3], distances.path_to(&3).unwrap().nodes);
+        assert_eq!(None, distances.get(&4));
+    }
+
+    #[test]
+    fn test_dijkstra_takes_cheapest_route() {
+        let path = dijkstra([0], weighted, |&n| n == 3).unwrap();
+        assert_eq!((vec![0, 1, 2, 3], 3), (path.nodes, path.cost));
+    }
+
+    #[test]
+    fn test_dijkstra_reachable() {
+        let distances = dijkstra_reachable([0], weighted);
+        assert_eq!(Some(3), distances.get(&3));
+        assert_eq!(vec![0, 1, 2, 3], distances.path_to(&3).unwrap().nodes);
+        assert_eq!(4, distances.iter().count());
+    }
+
+    #[test]
+    fn test_multiple_starts() {
+        let path = dijkstra([0, 2], weighted, |&n| n == 3).unwrap();
+        assert_eq!((vec![2, 3], 1), (path.nodes, path.cost));
+        assert_eq!(None, dijkstra([0], weighted, |&n| n == 4));
+    }
+
+    #[test]
+    fn test_astar_around_wall() {
+        // A wall at x = 2 from y = -5 to 5 in an otherwise open 21 by 21 area
+        let open = |p: &Point| p.x.abs() <= 10 && p.y.abs() <= 10 && !(p.x == 2 && p.y.abs() <= 5);
+        let goal = Point::new(4, 0);
+        let path = astar(
+            [Point::ORIGIN],
+            |p| {
+                p.neighbours4()
+                    .filter(open)
+                    .map(|n| (n, 1))
+                    .collect::<Vec<_>>()
+            },
+            |p| p.manhattan(goal),
+            |&p| p == goal,
+        )
+        .unwrap();
+        assert_eq!(16, path.cost);
+        assert_eq!(17, path.nodes.len());
+        assert!(path.nodes.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+    }
+}
@@ -0,0 +1,45 @@
+use std::fmt::Display;
+
+use crate::ParseError;
+
+/// A day's puzzle, split into a shared parse stage and the two parts answered from its result.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Output1: Answer;
+    type Output2: Answer;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(&self, input: &Self::Input) -> Self::Output1;
+    fn part2(&self, input: &Self::Input) -> Self::Output2;
+}
+
+/// What a part produces: the answer to submit, or for parts that can fail on some inputs, why
+/// there is none.
+pub trait Answer {
+    fn to_answer(&self) -> Result<String, String>;
+}
+
+macro_rules! impl_answer {
+    ($($t:ty),+) => {
+        $(
+            impl Answer for $t {
+                fn to_answer(&self) -> Result<String, String> {
+                    Ok(self.to_string())
+                }
+            }
+        )+
+    };
+}
+
+impl_answer!(i32, i64, i128, u32, u64, u128, usize, String);
+
+impl<T: Display, E: Display> Answer for Result<T, E> {
+    fn to_answer(&self) -> Result<String, String> {
+        match self {
+            Ok(answer) => Ok(answer.to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{ParseError, Solution};
+use serde::{Deserialize, Serialize};
+
+use crate::table;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The wall time of every run of each stage of one day.
+pub struct Timings {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    pub part2: Vec<Duration>,
+}
+
+impl Timings {
+    pub fn measurements(&self, day: u8) -> Vec<Measurement> {
+        [
+            (Stage::Parse, &self.parse),
+            (Stage::Part1, &self.part1),
+            (Stage::Part2, &self.part2),
+        ]
+        .into_iter()
+        .map(|(stage, times)| Measurement::new(day, stage, times))
+        .collect()
+    }
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> Duration {
+    let start = Instant::now();
+    black_box(f());
+    start.elapsed()
+}
+
+pub fn measure<S: Solution>(solution: &S, input: &str, runs: usize) -> Result<Timings, ParseError> {
+    let parsed = solution.parse(input)?;
+
+    Ok(Timings {
+        parse: (0..runs)
+            .map(|_| time(|| solution.parse(black_box(input))))
+            .collect(),
+        part1: (0..runs)
+            .map(|_| time(|| solution.part1(black_box(&parsed))))
+            .collect(),
+        part2: (0..runs)
+            .map(|_| time(|| solution.part2(black_box(&parsed))))
+            .collect(),
+    })
+}
+
+/// Summary of the runs of one stage, in nanoseconds so reports stay easy to diff and plot.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline_median_ns: Option<u64>,
+}
+
+impl Measurement {
+    pub fn new(day: u8, stage: Stage, times: &[Duration]) -> Self {
+        let mut times: Vec<u64> = times.iter().map(|t| t.as_nanos() as u64).collect();
+        times.sort_unstable();
+
+        Measurement {
+            day,
+            stage,
+            runs: times.len(),
+            min_ns: times.first().copied().unwrap_or_default(),
+            median_ns: times.get(times.len() / 2).copied().unwrap_or_default(),
+            max_ns: times.last().copied().unwrap_or_default(),
+            baseline_median_ns: None,
+        }
+    }
+
+    /// How much slower the median got compared to the baseline, as a fraction.
+    pub fn change(&self) -> Option<f64> {
+        self.baseline_median_ns
+            .filter(|&baseline| baseline > 0)
+            .map(|baseline| (self.median_ns as f64 - baseline as f64) / baseline as f64)
+    }
+}
+
+/// Looks up the baseline median of every measured stage that the baseline also measured.
+pub fn compare(measurements: &mut [Measurement], baseline: &[Measurement]) {
+    for measurement in measurements {
+        measurement.baseline_median_ns = baseline
+            .iter()
+            .find(|b| b.day == measurement.day && b.stage == measurement.stage)
+            .map(|b| b.median_ns);
+    }
+}
+
+pub fn to_json(measurements: &[Measurement]) -> String {
+    serde_json::to_string_pretty(measurements).expect("measurements are always serializable")
+}
+
+pub fn from_json(report: &str) -> serde_json::Result<Vec<Measurement>> {
+    serde_json::from_str(report)
+}
+
+pub fn to_csv(measurements: &[Measurement]) -> String {
+    let mut out = String::from("day,stage,runs,min_ns,median_ns,max_ns,baseline_median_ns\n");
+    for m in measurements {
+        let baseline = m
+            .baseline_median_ns
+            .map_or(String::new(), |b| b.to_string());
+        out.push_str(&format!(
+            "{},{},{},{},{},{},{baseline}\n",
+            m.day, m.stage, m.runs, m.min_ns, m.median_ns, m.max_ns
+        ));
+    }
+    out
+}
+
+pub fn table(measurements: &[Measurement]) -> String {
+    let duration = |ns: u64| format!("{:.2?}", Duration::from_nanos(ns));
+    let rows: Vec<Vec<String>> = measurements
+        .iter()
+        .map(|m| {
+            vec![
+                m.day.to_string(),
+                m.stage.to_string(),
+                duration(m.min_ns),
+                duration(m.median_ns),
+                duration(m.max_ns),
+                m.baseline_median_ns.map_or(String::new(), duration),
+                m.change()
+                    .map_or(String::new(), |c| format!("{:+.1}%", c * 100.0)),
+            ]
+        })
+        .collect();
+
+    table::render(
+        &["day", "stage", "min", "median", "max", "baseline", "change"],
+        &rows,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{compare, from_json, to_csv, to_json, Measurement, Stage};
+
+    fn measurement(day: u8, stage: Stage, times: &[u64]) -> Measurement {
+        let times: Vec<Duration> = times.iter().map(|&t| Duration::from_nanos(t)).collect();
+        Measurement::new(day, stage, &times)
+    }
+
+    #[test]
+    fn test_summarises_runs() {
+        let m = measurement(1, Stage::Parse, &[30, 10, 50, 20, 40]);
+        assert_eq!((5, 10, 30, 50), (m.runs, m.min_ns, m.median_ns, m.max_ns));
+    }
+
+    #[test]
+    fn test_compare_with_baseline() {
+        let baseline = vec![measurement(1, Stage::Part1, &[100])];
+        let mut current = vec![
+            measurement(1, Stage::Part1, &[150]),
+            measurement(1, Stage::Part2, &[10]),
+        ];
+        compare(&mut current, &baseline);
+
+        assert_eq!(Some(0.5), current[0].change());
+        assert_eq!(None, current[1].change());
+        assert!(to_csv(&current).ends_with("1,part1,1,150,150,150,100\n1,part2,1,10,10,10,\n"));
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let report = vec![measurement(12, Stage::Part2, &[3, 1, 2])];
+        assert_eq!(report, from_json(&to_json(&report)).unwrap());
+    }
+}
@@ -0,0 +1,6 @@
+pub mod bench;
+pub mod render;
+pub mod scaffold;
+pub mod solutions;
+mod table;
+pub mod verify;
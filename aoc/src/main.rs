@@ -0,0 +1,435 @@
+use std::{
+    fs,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc::{
+    bench,
+    render::{self, ImageFormat},
+    scaffold, solutions,
+    verify::{self, Manifest},
+};
+use aoc_common::{Point, Solution};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use day12::{ClimbRule, Connectivity};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day's puzzle and print the answers
+    Run {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=i64::from(solutions::LAST_DAY)))]
+        day: u8,
+        /// Only solve this part instead of both
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input file, or `-` to read from stdin [default: dayN/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// List every overlapping pair of day 4 with the sections they share, before the answers
+        #[arg(long)]
+        list_overlaps: bool,
+        #[command(flatten)]
+        climb: ClimbArgs,
+    },
+    /// Check the solutions against the accepted answers
+    Verify {
+        /// Only check this day
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=i64::from(solutions::LAST_DAY)))]
+        day: Option<u8>,
+        /// Answers manifest; inputs are looked up relative to it
+        #[arg(long, default_value = "answers.toml")]
+        manifest: PathBuf,
+    },
+    /// Time the parse, part 1 and part 2 stages of each day
+    Bench {
+        /// Only time this day
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=i64::from(solutions::LAST_DAY)))]
+        day: Option<u8>,
+        /// How many times each stage is run
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+        /// Earlier JSON report, e.g. saved with `--format json`, to compare the medians against
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+    },
+    /// Draw a day's puzzle input, for the days that have a renderer
+    Render {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=i64::from(solutions::LAST_DAY)))]
+        day: u8,
+        /// Puzzle input file, or `-` to read from stdin [default: dayN/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// How many positions wide and high every drawn cell is [default: about 100 cells across]
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        scale: Option<u32>,
+        /// Region to draw as `X1,Y1,X2,Y2` [default: the day's own]
+        #[arg(long, value_parser = parse_area)]
+        area: Option<(Point, Point)>,
+        /// Draw the distances to the goal instead of the route, for day 12
+        #[arg(long)]
+        heatmap: bool,
+        #[arg(long, value_enum, default_value_t = Picture::Ansi)]
+        format: Picture,
+        /// Image file to write instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+        #[command(flatten)]
+        climb: ClimbArgs,
+    },
+    /// Step through the day 10 program, reading debugger commands from stdin
+    Debug {
+        /// Program file [default: day10/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Create the crate for the next day from the template and register it with the runner
+    New {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+/// Rules for walking the hill of day 12
+#[derive(Args)]
+#[command(next_help_heading = "Day 12 climbing rules")]
+struct ClimbArgs {
+    /// Highest elevation gain allowed in one step [default: 1]
+    #[arg(long)]
+    max_ascent: Option<u8>,
+    /// Steepest drop allowed in one step [default: any]
+    #[arg(long)]
+    max_descent: Option<u8>,
+    /// Also allow diagonal steps
+    #[arg(long)]
+    diagonal: bool,
+    /// Cost every unit of elevation gained or lost adds to a step [default: 0]
+    #[arg(long)]
+    elevation_cost: Option<usize>,
+}
+
+impl ClimbArgs {
+    /// The first rule given on the command line, if any.
+    fn given(&self) -> Option<&'static str> {
+        [
+            ("--max-ascent", self.max_ascent.is_some()),
+            ("--max-descent", self.max_descent.is_some()),
+            ("--diagonal", self.diagonal),
+            ("--elevation-cost", self.elevation_cost.is_some()),
+        ]
+        .into_iter()
+        .find_map(|(flag, given)| given.then_some(flag))
+    }
+}
+
+impl From<ClimbArgs> for ClimbRule {
+    fn from(args: ClimbArgs) -> Self {
+        let puzzle = ClimbRule::default();
+        ClimbRule {
+            max_ascent: args.max_ascent.unwrap_or(puzzle.max_ascent),
+            max_descent: args.max_descent,
+            connectivity: if args.diagonal {
+                Connectivity::Eight
+            } else {
+                puzzle.connectivity
+            },
+            elevation_cost: args.elevation_cost.unwrap_or(puzzle.elevation_cost),
+        }
+    }
+}
+
+/// Rejects the first flag that is given but belongs to another day, as it would have no effect.
+fn check_flags(day: u8, flags: &[(Option<&str>, u8)]) -> Result<(), String> {
+    for &(flag, flag_day) in flags {
+        if let Some(flag) = flag.filter(|_| flag_day != day) {
+            return Err(format!("{flag} only applies to day {flag_day}"));
+        }
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Picture {
+    Ansi,
+    Ppm,
+    Pgm,
+}
+
+impl From<Picture> for ImageFormat {
+    fn from(picture: Picture) -> Self {
+        match picture {
+            Picture::Ansi => ImageFormat::Ansi,
+            Picture::Ppm => ImageFormat::Ppm,
+            Picture::Pgm => ImageFormat::Pgm,
+        }
+    }
+}
+
+fn parse_area(area: &str) -> Result<(Point, Point), String> {
+    let coordinates = area
+        .split(',')
+        .map(|c| c.trim().parse::<i64>().map_err(|e| format!("{c:?}: {e}")))
+        .collect::<Result<Vec<_>, _>>()?;
+    match coordinates[..] {
+        [x1, y1, x2, y2] => Ok((Point::new(x1, y1), Point::new(x2, y2))),
+        _ => Err("expected four coordinates, X1,Y1,X2,Y2".to_owned()),
+    }
+}
+
+fn read_input(day: u8, input: Option<PathBuf>) -> io::Result<String> {
+    let path = match input {
+        Some(path) if path.as_os_str() == "-" => {
+            let mut buffer = String::new();
+            io::stdin().read_to_string(&mut buffer)?;
+            return Ok(buffer);
+        }
+        Some(path) => path,
+        None => PathBuf::from(format!("day{day}/input.txt")),
+    };
+
+    fs::read_to_string(&path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))
+}
+
+fn read_report(path: PathBuf) -> io::Result<Vec<bench::Measurement>> {
+    let report = fs::read_to_string(&path)?;
+    bench::from_json(&report).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {e}", path.display()),
+        )
+    })
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            list_overlaps,
+            climb,
+        } => {
+            let flags = [
+                (list_overlaps.then_some("--list-overlaps"), 4),
+                (climb.given(), 12),
+            ];
+            if let Err(e) = check_flags(day, &flags) {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+            let input = match read_input(day, input) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("Failed to read input: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            if list_overlaps {
+                match solutions::list_overlaps(&input) {
+                    Ok(listing) => print!("{listing}"),
+                    Err(e) => {
+                        eprintln!("Failed to parse input: {e}");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            let parts = part.map_or(vec![1, 2], |part| vec![part]);
+            let variant = solutions::Variant {
+                climb_rule: climb.into(),
+            };
+            let answers = match solutions::run_variant(day, &parts, &input, &variant) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    eprintln!("Failed to parse input: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let mut solved = true;
+            for (part, answer) in parts.iter().zip(answers) {
+                match answer {
+                    Ok(answer) => println!("{answer}"),
+                    Err(e) => {
+                        eprintln!("No answer for part {part}: {e}");
+                        solved = false;
+                    }
+                }
+            }
+            if !solved {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Verify { day, manifest } => {
+            let answers = match Manifest::load(&manifest) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    eprintln!("Failed to read answers: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let root = manifest.parent().unwrap_or(Path::new(""));
+            let outcomes = verify::verify(&answers, root, day);
+            print!("{}", verify::table(&outcomes));
+
+            let passed = outcomes.iter().filter(|o| o.passed()).count();
+            println!("{passed} of {} answers verified", outcomes.len());
+            if passed != outcomes.len() {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Bench {
+            day,
+            runs,
+            format,
+            baseline,
+        } => {
+            let baseline = match baseline.map(read_report).transpose() {
+                Ok(baseline) => baseline,
+                Err(e) => {
+                    eprintln!("Failed to read baseline: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            // Days without an input yet are skipped, and a day that fails doesn't stop the others
+            let mut measurements = Vec::new();
+            let mut failed = false;
+            for day in day.map_or(1..=solutions::LAST_DAY, |day| day..=day) {
+                let input = match read_input(day, None) {
+                    Ok(input) => input,
+                    Err(e) => {
+                        eprintln!("Skipping day {day}, failed to read input: {e}");
+                        failed |= e.kind() != io::ErrorKind::NotFound;
+                        continue;
+                    }
+                };
+                match solutions::bench(day, &input, runs as usize) {
+                    Ok(timings) => measurements.extend(timings.measurements(day)),
+                    Err(e) => {
+                        eprintln!("Skipping day {day}, failed to parse input: {e}");
+                        failed = true;
+                    }
+                }
+            }
+            if measurements.is_empty() {
+                return ExitCode::FAILURE;
+            }
+
+            if let Some(baseline) = baseline {
+                bench::compare(&mut measurements, &baseline);
+            }
+            match format {
+                Format::Table => print!("{}", bench::table(&measurements)),
+                Format::Json => println!("{}", bench::to_json(&measurements)),
+                Format::Csv => print!("{}", bench::to_csv(&measurements)),
+            }
+            if failed {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Render {
+            day,
+            input,
+            scale,
+            area,
+            heatmap,
+            format,
+            output,
+            climb,
+        } => {
+            let flags = [
+                (scale.map(|_| "--scale"), 15),
+                (area.map(|_| "--area"), 15),
+                (heatmap.then_some("--heatmap"), 12),
+                (climb.given(), 12),
+            ];
+            if let Err(e) = check_flags(day, &flags) {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+            let input = match read_input(day, input) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("Failed to read input: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let options = render::Options {
+                scale,
+                area,
+                heatmap,
+                format: format.into(),
+                climb_rule: climb.into(),
+            };
+            let picture = match render::render(day, &input, &options) {
+                Ok(picture) => picture,
+                Err(e) => {
+                    eprintln!("Failed to render: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let written = match output {
+                Some(path) => fs::write(&path, picture)
+                    .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display()))),
+                None => io::stdout().write_all(&picture),
+            };
+            if let Err(e) = written {
+                eprintln!("Failed to write picture: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Debug { input } => {
+            let program = match read_input(10, input) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("Failed to read input: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let program = match day10::Day10.parse(&program) {
+                Ok(program) => program,
+                Err(e) => {
+                    eprintln!("Failed to parse input: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            if let Err(e) = day10::debugger::session(&program, io::stdin().lock(), io::stdout()) {
+                eprintln!("Debugger failed: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::New { day } => match scaffold::new_day(Path::new(""), day) {
+            Ok(written) => {
+                written
+                    .iter()
+                    .for_each(|path| println!("wrote {}", path.display()));
+                println!("Fill in day{day}/samples/ to make its sample test pass");
+            }
+            Err(e) => {
+                eprintln!("Failed to create day {day}: {e}");
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,159 @@
+use std::fmt;
+
+use aoc_common::{image, Interval, ParseError, Point, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ansi,
+    Ppm,
+    Pgm,
+}
+
+pub struct Options {
+    /// How many positions wide and high every drawn cell is, if not enough to fit about
+    /// [`DEFAULT_CELLS`] across the area
+    pub scale: Option<u32>,
+    /// Opposite corners of the region to draw, instead of the day's own default
+    pub area: Option<(Point, Point)>,
+    /// Draw the distances to the goal instead of the route, for days that search for one
+    pub heatmap: bool,
+    pub format: ImageFormat,
+    /// How the day 12 hill may be climbed
+    pub climb_rule: day12::ClimbRule,
+}
+
+/// How many cells fit across the longer side of the area when no scale is given.
+pub const DEFAULT_CELLS: u64 = 100;
+/// The most cells a picture may have, so a small scale over a huge area fails instead of
+/// running out of memory.
+pub const MAX_CELLS: u64 = 4_000_000;
+
+#[derive(Debug)]
+pub enum Error {
+    Unsupported(u8),
+    Parse(ParseError),
+    TooLarge { width: u64, height: u64 },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Unsupported(day) => write!(f, "day {day} has no renderer"),
+            Error::Parse(e) => write!(f, "{e}"),
+            Error::TooLarge { width, height } => write!(
+                f,
+                "{width} by {height} cells is more than the {MAX_CELLS} a picture may have, \
+                 try a larger scale"
+            ),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+/// Draws a picture of a day's puzzle input, in the requested format.
+pub fn render(day: u8, input: &str, options: &Options) -> Result<Vec<u8>, Error> {
+    match day {
+        12 => render_day12(input, options),
+        15 => render_day15(input, options),
+        day => Err(Error::Unsupported(day)),
+    }
+}
+
+fn render_day12(input: &str, options: &Options) -> Result<Vec<u8>, Error> {
+    use day12::{part1, part2, render, Day12};
+
+    let map = Day12::default().parse(input)?;
+    let rule = options.climb_rule;
+    if options.heatmap {
+        let heat = render::heat(&part2::descend(&map, &rule).distances);
+        return Ok(match options.format {
+            ImageFormat::Ansi => image::to_ansi(&heat, |&colour| ('█', colour)).into_bytes(),
+            ImageFormat::Ppm => image::to_ppm(&heat, |&colour| colour),
+            ImageFormat::Pgm => image::to_pgm(&heat, |&colour| colour),
+        });
+    }
+
+    // Without a route under the rule there is still the hill to see
+    let route = part1::shortest_route(&map, &rule).unwrap_or_default();
+    let tiles = render::route_map(&map, &route);
+    Ok(match options.format {
+        ImageFormat::Ansi => {
+            image::to_ansi(&tiles, |tile| (tile.glyph(), tile.colour())).into_bytes()
+        }
+        ImageFormat::Ppm => image::to_ppm(&tiles, |tile| tile.colour()),
+        ImageFormat::Pgm => image::to_pgm(&tiles, |tile| tile.colour()),
+    })
+}
+
+fn render_day15(input: &str, options: &Options) -> Result<Vec<u8>, Error> {
+    use day15::{part2, render::Cell, Day15, SearchArea};
+
+    let sensors = Day15::default().parse(input)?;
+    let area = options
+        .area
+        .map_or(Day15::default().search_area, |(a, b)| SearchArea {
+            x: Interval::new(a.x.min(b.x), a.x.max(b.x)),
+            y: Interval::new(a.y.min(b.y), a.y.max(b.y)),
+        });
+    let scale = match options.scale {
+        Some(scale) => scale,
+        None => {
+            let longest = area.x.length().max(area.y.length());
+            u32::try_from(longest.div_ceil(u128::from(DEFAULT_CELLS))).unwrap_or(u32::MAX)
+        }
+    };
+    let cells =
+        |length: u128| u64::try_from(length.div_ceil(u128::from(scale))).unwrap_or(u64::MAX);
+    let (width, height) = (cells(area.x.length()), cells(area.y.length()));
+    if width.saturating_mul(height) > MAX_CELLS {
+        return Err(Error::TooLarge { width, height });
+    }
+
+    let gap = part2::find_gap_by_boundaries(&sensors, area);
+    let map = day15::render::coverage_map(&sensors, area, scale, gap);
+
+    Ok(match options.format {
+        ImageFormat::Ansi => {
+            image::to_ansi(&map, |cell| (cell.glyph(), cell.colour())).into_bytes()
+        }
+        ImageFormat::Ppm => image::to_ppm(&map, |cell: &Cell| cell.colour()),
+        ImageFormat::Pgm => image::to_pgm(&map, |cell: &Cell| cell.colour()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render, Error, ImageFormat, Options};
+
+    fn options(scale: Option<u32>) -> Options {
+        Options {
+            scale,
+            area: None,
+            heatmap: false,
+            format: ImageFormat::Pgm,
+            climb_rule: day12::ClimbRule::default(),
+        }
+    }
+
+    #[test]
+    fn test_default_scale_fits_the_area() {
+        let input = include_str!("../../day15/samples/sample.txt");
+        let picture = render(15, input, &options(None)).unwrap();
+        // 4,000,001 positions across in cells of 40,001
+        assert!(picture.starts_with(b"P5\n100 100\n"));
+
+        let error = render(15, input, &options(Some(1))).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::TooLarge {
+                width: 4_000_001,
+                height: 4_000_001
+            }
+        ));
+    }
+}
@@ -0,0 +1,170 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::solutions::LAST_DAY;
+
+const TEMPLATE: [(&str, &str); 4] = [
+    ("Cargo.toml", include_str!("../templates/day/Cargo.toml")),
+    ("src/lib.rs", include_str!("../templates/day/src/lib.rs")),
+    (
+        "src/part1.rs",
+        include_str!("../templates/day/src/part1.rs"),
+    ),
+    (
+        "src/part2.rs",
+        include_str!("../templates/day/src/part2.rs"),
+    ),
+];
+
+/// Empty until the puzzle is out, so the generated sample test fails until it is filled in.
+const SAMPLES: [&str; 3] = ["sample.txt", "sample.part1.txt", "sample.part2.txt"];
+
+/// Creates the crate for `day` in the workspace at `root` and registers it with the runner.
+/// Days are added in order, and an existing day is never overwritten. Returns the files written.
+pub fn new_day(root: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    let dir = root.join(format!("day{day}"));
+    if day <= LAST_DAY || dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("day {day} already exists"),
+        ));
+    }
+    // The runner only knows the days up to `LAST_DAY`, so a gap would leave days unreachable
+    if day != LAST_DAY + 1 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("day {} has to be added before day {day}", LAST_DAY + 1),
+        ));
+    }
+
+    // Everything is prepared before writing, so a workspace that doesn't look as expected is
+    // left untouched
+    let registrations = [
+        (
+            "Cargo.toml",
+            register_workspace as fn(&str, u8) -> Option<String>,
+        ),
+        ("aoc/Cargo.toml", register_dependency),
+        ("aoc/src/solutions.rs", register_solution),
+    ]
+    .into_iter()
+    .map(|(file, register)| {
+        let path = root.join(file);
+        let text = fs::read_to_string(&path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+        let text = register(&text, day).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: day {LAST_DAY} is not registered here", path.display()),
+            )
+        })?;
+        Ok((path, text))
+    })
+    .collect::<io::Result<Vec<_>>>()?;
+
+    let mut written = Vec::new();
+    for (file, template) in TEMPLATE {
+        let path = dir.join(file);
+        fs::create_dir_all(path.parent().expect("template files are inside the day"))?;
+        fs::write(&path, template.replace("{{day}}", &day.to_string()))?;
+        written.push(path);
+    }
+    fs::create_dir(dir.join("samples"))?;
+    for sample in SAMPLES {
+        let path = dir.join("samples").join(sample);
+        fs::write(&path, "")?;
+        written.push(path);
+    }
+    for (path, text) in registrations {
+        fs::write(&path, text)?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+/// Inserts `line` directly after the first line equal to `after`.
+fn insert_after(text: &str, after: &str, line: &str) -> Option<String> {
+    let start = text.find(&format!("{after}\n"))? + after.len() + 1;
+    Some(format!("{}{line}\n{}", &text[..start], &text[start..]))
+}
+
+fn register_workspace(text: &str, day: u8) -> Option<String> {
+    let last = LAST_DAY;
+    let text = insert_after(
+        text,
+        &format!("    \"day{last}\","),
+        &format!("    \"day{day}\","),
+    )?;
+    insert_after(
+        &text,
+        &format!("day{last} = {{ path = \"day{last}\" }}"),
+        &format!("day{day} = {{ path = \"day{day}\" }}"),
+    )
+}
+
+fn register_dependency(text: &str, day: u8) -> Option<String> {
+    insert_after(
+        text,
+        &format!("day{LAST_DAY}.workspace = true"),
+        &format!("day{day}.workspace = true"),
+    )
+}
+
+fn register_solution(text: &str, day: u8) -> Option<String> {
+    let text = text.replacen(
+        &format!("pub const LAST_DAY: u8 = {LAST_DAY};"),
+        &format!("pub const LAST_DAY: u8 = {day};"),
+        1,
+    );
+    let fallback = "            day => unreachable!";
+    let arm = format!(
+        "            {day} => {{\n                let $solution = &day{day}::Day{day};\n                $body\n            }}\n"
+    );
+    let at = text.find(fallback)?;
+    Some(format!("{}{arm}{}", &text[..at], &text[at..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, io, path::Path};
+
+    use super::new_day;
+    use crate::solutions::LAST_DAY;
+
+    #[test]
+    fn test_new_day() {
+        let real = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/solutions.rs"] {
+            fs::copy(real.join(file), root.join(file)).unwrap();
+        }
+
+        let day = LAST_DAY + 1;
+        let written = new_day(&root, day);
+        let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        let solutions = fs::read_to_string(root.join("aoc/src/solutions.rs")).unwrap();
+        let lib = fs::read_to_string(root.join(format!("day{day}/src/lib.rs")));
+        let again = new_day(&root, day).unwrap_err();
+        let skipped = new_day(&root, day + 2).unwrap_err();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(10, written.unwrap().len());
+        assert!(workspace.contains(&format!("    \"day{day}\",\n]")));
+        assert!(workspace.contains(&format!("day{day} = {{ path = \"day{day}\" }}\n")));
+        assert!(solutions.contains(&format!("let $solution = &day{day}::Day{day};")));
+        assert!(solutions.contains(&format!("pub const LAST_DAY: u8 = {day};")));
+        assert!(lib.unwrap().contains(&format!("const DAY: u8 = {day};")));
+        assert_eq!(io::ErrorKind::AlreadyExists, again.kind());
+        assert_eq!(io::ErrorKind::InvalidInput, skipped.kind());
+    }
+
+    #[test]
+    fn test_refuses_existing_day() {
+        let error = new_day(Path::new("does-not-exist"), LAST_DAY).unwrap_err();
+        assert_eq!(io::ErrorKind::AlreadyExists, error.kind());
+    }
+}
@@ -0,0 +1,151 @@
+use aoc_common::{Answer, ParseError, Solution};
+
+use crate::bench::{self, Timings};
+
+/// Days are solved from 1 up to and including this one.
+pub const LAST_DAY: u8 = 15;
+
+/// Binds `$solution` to the solver for `$day` and evaluates `$body` with it.
+macro_rules! with_solution {
+    ($day:expr, $solution:ident => $body:expr) => {
+        match $day {
+            1 => {
+                let $solution = &day1::Day1;
+                $body
+            }
+            2 => {
+                let $solution = &day2::Day2;
+                $body
+            }
+            3 => {
+                let $solution = &day3::Day3;
+                $body
+            }
+            4 => {
+                let $solution = &day4::Day4;
+                $body
+            }
+            5 => {
+                let $solution = &day5::Day5;
+                $body
+            }
+            6 => {
+                let $solution = &day6::Day6;
+                $body
+            }
+            7 => {
+                let $solution = &day7::Day7;
+                $body
+            }
+            8 => {
+                let $solution = &day8::Day8;
+                $body
+            }
+            9 => {
+                let $solution = &day9::Day9;
+                $body
+            }
+            10 => {
+                let $solution = &day10::Day10;
+                $body
+            }
+            11 => {
+                let $solution = &day11::Day11;
+                $body
+            }
+            12 => {
+                let $solution = &day12::Day12::default();
+                $body
+            }
+            13 => {
+                let $solution = &day13::Day13;
+                $body
+            }
+            14 => {
+                let $solution = &day14::Day14;
+                $body
+            }
+            15 => {
+                let $solution = &day15::Day15::default();
+                $body
+            }
+            day => unreachable!("day {day} has no solution"),
+        }
+    };
+}
+
+/// Variations on the puzzles, for the days that support them.
+#[derive(Default)]
+pub struct Variant {
+    pub climb_rule: day12::ClimbRule,
+}
+
+/// The answer to each requested part, or why that part has none.
+pub type Answers = Vec<Result<String, String>>;
+
+pub fn run(day: u8, parts: &[u8], input: &str) -> Result<Answers, ParseError> {
+    run_variant(day, parts, input, &Variant::default())
+}
+
+/// Like [`run`], but solving the given variant of the puzzle.
+pub fn run_variant(
+    day: u8,
+    parts: &[u8],
+    input: &str,
+    variant: &Variant,
+) -> Result<Answers, ParseError> {
+    match day {
+        12 => {
+            let solution = &day12::Day12 {
+                rule: variant.climb_rule,
+            };
+            solve(solution, parts, input)
+        }
+        _ => with_solution!(day, solution => solve(solution, parts, input)),
+    }
+}
+
+/// Every overlapping pair of day 4 on a line of its own, with the sections they share.
+pub fn list_overlaps(input: &str) -> Result<String, ParseError> {
+    let pairs = day4::Day4.parse(input)?;
+    Ok(day4::find_overlaps(&pairs)
+        .map(|overlap| format!("{overlap}\n"))
+        .collect())
+}
+
+/// Times every stage of a day's solution `runs` times.
+pub fn bench(day: u8, input: &str, runs: usize) -> Result<Timings, ParseError> {
+    with_solution!(day, solution => bench::measure(solution, input, runs))
+}
+
+fn solve<S: Solution>(solution: &S, parts: &[u8], input: &str) -> Result<Answers, ParseError> {
+    let input = solution.parse(input)?;
+
+    Ok(parts
+        .iter()
+        .map(|part| match part {
+            1 => solution.part1(&input).to_answer(),
+            2 => solution.part2(&input).to_answer(),
+            _ => unreachable!("puzzles only have two parts"),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::list_overlaps;
+
+    #[test]
+    fn test_list_overlaps() {
+        let listing = list_overlaps(include_str!("../../day4/samples/sample.txt")).unwrap();
+        assert_eq!(
+            "\
+pair 3: 5-7,7-9 share 7-7
+pair 4: 2-8,3-7 share 3-7 (fully contained)
+pair 5: 6-6,4-6 share 6-6 (fully contained)
+pair 6: 2-6,4-8 share 4-6
+",
+            listing
+        );
+    }
+}
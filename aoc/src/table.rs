@@ -0,0 +1,40 @@
+/// Lays out rows of cells in left-aligned columns under a header.
+pub fn render(header: &[&str], rows: &[Vec<String>]) -> String {
+    let header: Vec<String> = header.iter().map(|cell| cell.to_string()).collect();
+
+    let mut widths = vec![0; header.len()];
+    for row in std::iter::once(&header).chain(rows) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut out = String::new();
+    for row in std::iter::once(&header).chain(rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:width$}"))
+            .collect();
+        out.push_str(line.join("  ").trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render;
+
+    #[test]
+    fn test_render() {
+        let rows = vec![
+            vec!["1".to_owned(), "ok".to_owned()],
+            vec!["15".to_owned(), "".to_owned()],
+        ];
+        assert_eq!(
+            "day  status\n1    ok\n15\n",
+            render(&["day", "status"], &rows)
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{solutions, table};
+
+/// The accepted answers, each tied to the hash of the input it was accepted for.
+#[derive(Debug, Deserialize)]
+pub struct Manifest {
+    #[serde(rename = "answer")]
+    pub answers: Vec<Answer>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    /// Path of the puzzle input, relative to the manifest
+    pub input: PathBuf,
+    pub hash: String,
+    pub expected: String,
+}
+
+impl Manifest {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+        Manifest::parse(&text).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {e}", path.display()),
+            )
+        })
+    }
+
+    /// Reads a manifest, rejecting answers for days or parts that have no solution.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let manifest: Manifest = toml::from_str(text).map_err(|e| e.to_string())?;
+        for (i, answer) in manifest.answers.iter().enumerate() {
+            if !(1..=solutions::LAST_DAY).contains(&answer.day) {
+                return Err(format!(
+                    "answer {} is for day {}, but only days 1 to {} are solved",
+                    i + 1,
+                    answer.day,
+                    solutions::LAST_DAY
+                ));
+            }
+            if !(1..=2).contains(&answer.part) {
+                return Err(format!(
+                    "answer {} is for part {}, but puzzles only have parts 1 and 2",
+                    i + 1,
+                    answer.part
+                ));
+            }
+        }
+        Ok(manifest)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Wrong(String),
+    InputChanged(String),
+    Unreadable(String),
+    Unparsable(String),
+    Unsolved(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Correct => write!(f, "ok"),
+            Status::Wrong(_) => write!(f, "WRONG"),
+            Status::InputChanged(hash) => write!(f, "input changed, now hashes to {hash}"),
+            Status::Unreadable(e) => write!(f, "unreadable: {e}"),
+            Status::Unparsable(e) => write!(f, "parse error: {e}"),
+            Status::Unsolved(e) => write!(f, "no answer: {}", e.lines().next().unwrap_or_default()),
+        }
+    }
+}
+
+pub struct Outcome<'a> {
+    pub answer: &'a Answer,
+    pub status: Status,
+}
+
+impl Outcome<'_> {
+    pub fn passed(&self) -> bool {
+        self.status == Status::Correct
+    }
+}
+
+/// FNV-1a, which unlike the std hashers is guaranteed to stay the same between releases.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// Solves every answer in the manifest, or only those for `day`, against its recorded input.
+pub fn verify<'a>(manifest: &'a Manifest, root: &Path, day: Option<u8>) -> Vec<Outcome<'a>> {
+    manifest
+        .answers
+        .iter()
+        .filter(|answer| day.is_none_or(|day| answer.day == day))
+        .map(|answer| Outcome {
+            answer,
+            status: check(answer, root),
+        })
+        .collect()
+}
+
+fn check(answer: &Answer, root: &Path) -> Status {
+    let input = match fs::read_to_string(root.join(&answer.input)) {
+        Ok(input) => input,
+        Err(e) => return Status::Unreadable(e.to_string()),
+    };
+
+    let hash = input_hash(&input);
+    if hash != answer.hash {
+        return Status::InputChanged(hash);
+    }
+
+    match solutions::run(answer.day, &[answer.part], &input) {
+        Ok(mut answers) => match answers.pop() {
+            Some(Ok(actual)) if actual == answer.expected => Status::Correct,
+            Some(Ok(actual)) => Status::Wrong(actual),
+            Some(Err(e)) => Status::Unsolved(e),
+            None => unreachable!("one part was requested"),
+        },
+        Err(e) => Status::Unparsable(e.to_string()),
+    }
+}
+
+/// Lays the outcomes out as a table, one row per answer.
+pub fn table(outcomes: &[Outcome]) -> String {
+    let rows: Vec<Vec<String>> = outcomes
+        .iter()
+        .map(|outcome| {
+            let answer = outcome.answer;
+            let actual = match &outcome.status {
+                Status::Correct => &answer.expected,
+                Status::Wrong(actual) => actual,
+                _ => "",
+            };
+            vec![
+                answer.day.to_string(),
+                answer.part.to_string(),
+                answer.input.display().to_string(),
+                cell(&answer.expected),
+                cell(actual),
+                outcome.status.to_string(),
+            ]
+        })
+        .collect();
+
+    table::render(
+        &["day", "part", "input", "expected", "actual", "status"],
+        &rows,
+    )
+}
+
+/// Keeps multi-line answers, like a drawn screen, on a single row of the table.
+fn cell(answer: &str) -> String {
+    const MAX_WIDTH: usize = 24;
+    let escaped = answer.replace('\n', "\\n");
+    if escaped.chars().count() > MAX_WIDTH {
+        let mut truncated: String = escaped.chars().take(MAX_WIDTH - 1).collect();
+        truncated.push('…');
+        truncated
+    } else {
+        escaped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{input_hash, table, verify, Manifest, Status};
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!("cbf29ce484222325", input_hash(""));
+        assert_eq!("af63dc4c8601ec8c", input_hash("a"));
+    }
+
+    #[test]
+    fn test_reports_mismatches() {
+        let manifest = Manifest::parse(
+            r#"
+            [[answer]]
+            day = 1
+            part = 1
+            input = "day1/input.txt"
+            hash = "0000000000000000"
+            expected = "1"
+            "#,
+        )
+        .unwrap();
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+        let outcomes = verify(&manifest, &root, None);
+        assert!(matches!(outcomes[0].status, Status::InputChanged(_)));
+        assert!(table(&outcomes).contains("input changed"));
+        assert!(verify(&manifest, &root, Some(2)).is_empty());
+    }
+
+    #[test]
+    fn test_rejects_unsolved_puzzles() {
+        let entry = |day, part| {
+            let text = format!(
+                "[[answer]]\nday = {day}\npart = {part}\ninput = \"input.txt\"\nhash = \"\"\nexpected = \"1\"\n"
+            );
+            Manifest::parse(&text).map(|_| ())
+        };
+        assert_eq!(Ok(()), entry(15, 2));
+        assert_eq!(
+            Err("answer 1 is for day 16, but only days 1 to 15 are solved".to_owned()),
+            entry(16, 1)
+        );
+        assert!(entry(0, 1).is_err());
+        assert_eq!(
+            Err("answer 1 is for part 3, but puzzles only have parts 1 and 2".to_owned()),
+            entry(1, 3)
+        );
+    }
+}
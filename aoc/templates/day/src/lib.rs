@@ -0,0 +1,28 @@
+use aoc_common::{ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    const DAY: u8 = {{day}};
+
+    type Input = Vec<String>;
+    type Output1 = Result<u64, &'static str>;
+    type Output2 = Result<u64, &'static str>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(aoc_common::lines(input).map(str::to_owned).collect())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<u64, &'static str> {
+        part1::run(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<u64, &'static str> {
+        part2::run(input)
+    }
+}
+
+aoc_common::sample_tests!(crate::Day{{day}});
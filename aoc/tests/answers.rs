@@ -0,0 +1,34 @@
+use std::path::{Path, PathBuf};
+
+use aoc::verify::{table, verify, Manifest, Outcome};
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn check_answers(keep: impl Fn(&Path) -> bool) {
+    let mut manifest = Manifest::load(&root().join("answers.toml")).unwrap();
+    manifest.answers.retain(|answer| keep(&answer.input));
+    assert!(!manifest.answers.is_empty());
+
+    let outcomes = verify(&manifest, &root(), None);
+    assert!(
+        outcomes.iter().all(Outcome::passed),
+        "answers differ from the manifest:\n{}",
+        table(&outcomes)
+    );
+}
+
+fn is_sample(input: &Path) -> bool {
+    input.parent().is_some_and(|dir| dir.ends_with("samples"))
+}
+
+#[test]
+fn test_samples() {
+    check_answers(is_sample);
+}
+
+#[test]
+fn test_inputs() {
+    check_answers(|input| !is_sample(input));
+}
@@ -0,0 +1,48 @@
+use aoc_common::{parse_field, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        aoc_common::blocks(input)
+            .map(|e| {
+                e.split_whitespace()
+                    .map(|i| parse_field::<i64>(Self::DAY, input, i))
+                    .sum()
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> i64 {
+        part1::run(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> i64 {
+        part2::run(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use crate::Day1;
+
+    #[test]
+    fn test_parse_invalid_item() {
+        let error = Day1.parse("1000\n2000\n\n3o00\n").unwrap_err();
+        assert_eq!((4, 1), (error.line(), error.column()));
+        assert_eq!("3o00", error.text());
+    }
+}
+
+aoc_common::sample_tests!(crate::Day1);
@@ -0,0 +1,3 @@
+pub fn run(elves: &[i64]) -> i64 {
+    *elves.iter().max().unwrap()
+}
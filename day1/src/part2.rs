@@ -0,0 +1,6 @@
+pub fn run(elves: &[i64]) -> i64 {
+    let mut elves = elves.to_vec();
+
+    elves.sort_by(|a, b| b.cmp(a));
+    elves.into_iter().take(3).sum::<i64>()
+}
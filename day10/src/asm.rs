@@ -0,0 +1,153 @@
+use aoc_common::{parse_field, ParseError, Solution};
+
+use crate::{
+    isa::{Instruction, Kind, Operand, Register, ISA},
+    Day10,
+};
+
+/// Turns source text into a program. Every line holds one instruction, blank lines are skipped
+/// and `;` starts a comment. Jump targets are instruction indices, where the index just past the
+/// last instruction ends the program.
+pub fn assemble(source: &str) -> Result<Vec<Instruction>, ParseError> {
+    let error = |span, message: String| ParseError::new(Day10::DAY, source, span, message);
+
+    let mut program = Vec::new();
+    let mut targets = Vec::new();
+    for line in source.lines() {
+        let code = line.split_once(';').map_or(line, |(code, _)| code).trim();
+        let mut words = code.split_whitespace();
+        let Some(name) = words.next() else {
+            continue;
+        };
+        let opcode = ISA
+            .iter()
+            .find(|opcode| opcode.name == name)
+            .ok_or_else(|| error(name, "unknown instruction".to_owned()))?;
+
+        let words: Vec<_> = words.collect();
+        if words.len() != opcode.operands.len() {
+            let kinds: Vec<_> = opcode.operands.iter().map(Kind::to_string).collect();
+            let expected = match &kinds[..] {
+                [] => "no operands".to_owned(),
+                kinds => kinds.join(" and "),
+            };
+            return Err(error(code, format!("`{name}` takes {expected}")));
+        }
+
+        let operands = opcode
+            .operands
+            .iter()
+            .zip(words)
+            .map(|(&kind, word)| {
+                let operand = match Register::from_name(word) {
+                    Some(register) => Operand::Register(register),
+                    None if kind == Kind::Register => {
+                        return Err(error(word, "expected a register, x, y or z".to_owned()))
+                    }
+                    None => Operand::Number(parse_field(Day10::DAY, source, word)?),
+                };
+                match kind {
+                    Kind::Target if !kind.accepts(operand) => Err(error(
+                        word,
+                        "expected the index of an instruction".to_owned(),
+                    )),
+                    Kind::Target => {
+                        targets.push(word);
+                        Ok(operand)
+                    }
+                    _ => Ok(operand),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        program.push(Instruction::new(opcode.op, &operands));
+    }
+
+    // Jumps may go forward, so targets can only be checked once every instruction is known
+    for target in targets {
+        let index: usize = parse_field(Day10::DAY, source, target)?;
+        if index > program.len() {
+            return Err(error(
+                target,
+                "jumps past the end of the program".to_owned(),
+            ));
+        }
+    }
+
+    Ok(program)
+}
+
+/// Writes `program` back as source, with the index of each instruction in a comment so jump
+/// targets can be followed.
+pub fn disassemble(program: &[Instruction]) -> String {
+    program
+        .iter()
+        .enumerate()
+        .map(|(index, instruction)| format!("{:<12} ; {index}\n", instruction.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::{assemble, disassemble};
+    use crate::isa::{Instruction, Op, Operand, Register};
+
+    #[test]
+    fn test_assemble() {
+        let source = "\
+; counts down from 3
+set y 3
+
+addx y  ; moves the sprite
+add y -1
+jnz y 1
+";
+        assert_eq!(
+            vec![
+                Instruction::new(
+                    Op::Set,
+                    &[Operand::Register(Register::Y), Operand::Number(3)]
+                ),
+                Instruction::new(Op::Addx, &[Operand::Register(Register::Y)]),
+                Instruction::new(
+                    Op::Add,
+                    &[Operand::Register(Register::Y), Operand::Number(-1)]
+                ),
+                Instruction::new(
+                    Op::Jnz,
+                    &[Operand::Register(Register::Y), Operand::Number(1)]
+                ),
+            ],
+            assemble(source).unwrap()
+        );
+    }
+
+    #[test_case("noop\nsub x 1", 2, 1, "unknown instruction at \"sub\""; "unknown instruction")]
+    #[test_case("noop\n  addx", 2, 3, "`addx` takes value at \"addx\""; "missing operand")]
+    #[test_case("noop 1", 1, 1, "`noop` takes no operands at \"noop 1\""; "extra operand")]
+    #[test_case("set 1 2", 1, 5, "expected a register, x, y or z at \"1\""; "not a register")]
+    #[test_case("addx w", 1, 6, "invalid digit found in string at \"w\""; "bad value")]
+    #[test_case("jz x -1", 1, 6, "expected the index of an instruction at \"-1\""; "negative target")]
+    #[test_case("jmp 3\nnoop", 1, 5, "jumps past the end of the program at \"3\""; "target past the end")]
+    fn test_assemble_error(source: &str, line: usize, column: usize, message: &str) {
+        let error = assemble(source).unwrap_err();
+        assert_eq!((line, column), (error.line(), error.column()));
+        assert_eq!(
+            format!("day 10, line {line}, column {column}: {message}"),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let source = "set z 2\nmulx z\njz x 4\njmp 0\nnoop\n";
+        let program = assemble(source).unwrap();
+        let listing = disassemble(&program);
+        assert_eq!(
+            "set z 2      ; 0\nmulx z       ; 1\njz x 4       ; 2\njmp 0        ; 3\nnoop         ; 4\n",
+            listing
+        );
+        assert_eq!(program, assemble(&listing).unwrap());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::isa::{Instruction, Register, Registers};
+
+/// The X register around one clock cycle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// Counted from 1, like in the puzzle
+    pub number: usize,
+    pub during: i32,
+    pub after: i32,
+}
+
+/// How many cycles a program may run for by default. Jumps can make a program loop forever, so
+/// the CPU gives up once its budget is spent.
+pub const DEFAULT_BUDGET: usize = 1_000_000;
+
+/// Runs a program one cycle at a time, yielding the register for every cycle until the last
+/// instruction completes or the cycle budget is spent.
+#[derive(Clone, Debug)]
+pub struct Cpu<'a> {
+    program: &'a [Instruction],
+    registers: Registers,
+    cycle: usize,
+    /// The most cycles to run for
+    budget: usize,
+    /// Index of the instruction being executed
+    pc: usize,
+    /// Cycles already spent on the instruction being executed
+    progress: usize,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(program: &'a [Instruction]) -> Self {
+        Cpu::with_budget(program, DEFAULT_BUDGET)
+    }
+
+    pub fn with_budget(program: &'a [Instruction], budget: usize) -> Self {
+        Cpu {
+            program,
+            registers: Registers::default(),
+            cycle: 0,
+            budget,
+            pc: 0,
+            progress: 0,
+        }
+    }
+
+    pub fn x(&self) -> i32 {
+        self.registers[Register::X]
+    }
+
+    pub fn registers(&self) -> Registers {
+        self.registers
+    }
+
+    /// The number of cycles completed so far.
+    pub fn elapsed(&self) -> usize {
+        self.cycle
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// Whether the next cycle starts an instruction, rather than continuing one that takes
+    /// several cycles.
+    pub fn at_instruction_start(&self) -> bool {
+        self.progress == 0
+    }
+
+    /// The instruction that the next cycle works on, unless the program has finished.
+    pub fn current(&self) -> Option<Instruction> {
+        self.program.get(self.pc).copied()
+    }
+
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Whether the CPU stopped because it spent its budget before the program finished.
+    pub fn out_of_budget(&self) -> bool {
+        self.cycle == self.budget && self.current().is_some()
+    }
+}
+
+impl Iterator for Cpu<'_> {
+    type Item = Cycle;
+
+    fn next(&mut self) -> Option<Cycle> {
+        if self.cycle == self.budget {
+            return None;
+        }
+        let instruction = self.current()?;
+        self.cycle += 1;
+        let during = self.x();
+
+        self.progress += 1;
+        if self.progress == instruction.cycles() {
+            self.pc = instruction
+                .execute(&mut self.registers)
+                .unwrap_or(self.pc + 1);
+            self.progress = 0;
+        }
+
+        Some(Cycle {
+            number: self.cycle,
+            during,
+            after: self.x(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cpu, Cycle, DEFAULT_BUDGET};
+    use crate::{asm::assemble, Instruction};
+
+    #[test]
+    fn test_trace() {
+        let program = [
+            Instruction::noop(),
+            Instruction::addx(3),
+            Instruction::addx(-5),
+        ];
+        let trace: Vec<_> = Cpu::new(&program)
+            .map(|Cycle { during, after, .. }| (during, after))
+            .collect();
+        assert_eq!(vec![(1, 1), (1, 1), (1, 4), (4, 4), (4, -1)], trace);
+    }
+
+    #[test]
+    fn test_state_between_cycles() {
+        let program = [Instruction::addx(3), Instruction::noop()];
+        let mut cpu = Cpu::new(&program);
+        cpu.next();
+        assert_eq!((1, 1, 0), (cpu.elapsed(), cpu.x(), cpu.pc()));
+        assert!(!cpu.at_instruction_start());
+        cpu.next();
+        assert_eq!((2, 4, 1), (cpu.elapsed(), cpu.x(), cpu.pc()));
+        assert!(cpu.at_instruction_start());
+        assert_eq!(Some(Instruction::noop()), cpu.current());
+        assert_eq!(Some(3), cpu.last().map(|cycle| cycle.number));
+    }
+
+    #[test]
+    fn test_loop() {
+        // Adds 2 to X three times, counting down in Y
+        let program = assemble("set y 3\naddx 2\nadd y -1\njnz y 1\nmulx -1\n").unwrap();
+        let mut cpu = Cpu::new(&program);
+        let cycles = cpu.by_ref().count();
+        assert_eq!(1 + 3 * (2 + 2 + 2) + 3, cycles);
+        assert_eq!(-7, cpu.x());
+        assert_eq!(0, cpu.registers()[crate::isa::Register::Y]);
+    }
+
+    #[test]
+    fn test_budget() {
+        let program = assemble("addx 1\njmp 0\n").unwrap();
+        let mut cpu = Cpu::with_budget(&program, 100);
+        assert_eq!(100, cpu.by_ref().count());
+        assert!(cpu.out_of_budget());
+        // 33 rounds of the loop and the first cycle of the next addx
+        assert_eq!(34, cpu.x());
+        assert_eq!(None, cpu.next());
+
+        let program = assemble("noop").unwrap();
+        let mut cpu = Cpu::with_budget(&program, 1);
+        assert_eq!(1, cpu.by_ref().count());
+        assert!(!cpu.out_of_budget());
+        assert_eq!(DEFAULT_BUDGET, Cpu::new(&program).budget());
+    }
+}
@@ -0,0 +1,93 @@
+use std::fmt;
+
+use aoc_common::Grid;
+
+use crate::cpu::Cycle;
+
+/// The screen, lit one pixel per cycle by a beam sweeping it row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Crt {
+    pixels: Grid<bool>,
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        Crt::new(40, 6)
+    }
+}
+
+impl Crt {
+    pub fn new(width: usize, height: usize) -> Self {
+        Crt {
+            pixels: Grid::new(width, height, false),
+        }
+    }
+
+    pub fn pixels(&self) -> &Grid<bool> {
+        &self.pixels
+    }
+
+    /// The pixel the beam draws during the cycle numbered `cycle`, starting over at the top left
+    /// once the screen is full.
+    pub fn beam(&self, cycle: usize) -> (usize, usize) {
+        let width = self.pixels.width();
+        let pixel = (cycle - 1) % (width * self.pixels.height());
+        (pixel % width, pixel / width)
+    }
+
+    /// Lights the pixel under the beam if the three pixel wide sprite, centred on the X register,
+    /// overlaps it.
+    pub fn draw(&mut self, cycle: &Cycle) {
+        let (x, y) = self.beam(cycle.number);
+        self.pixels[(x, y)] = (x as i64).abs_diff(i64::from(cycle.during)) <= 1;
+    }
+
+    pub fn render(&self, lit: char, dark: char) -> String {
+        self.pixels
+            .render(|_, &pixel| if pixel { lit } else { dark })
+    }
+}
+
+/// Draws a single frame, ignoring any cycles past the end of the screen.
+impl Extend<Cycle> for Crt {
+    fn extend<I: IntoIterator<Item = Cycle>>(&mut self, cycles: I) {
+        let frame = self.pixels.width() * self.pixels.height();
+        cycles
+            .into_iter()
+            .take(frame)
+            .for_each(|cycle| self.draw(&cycle));
+    }
+}
+
+impl fmt::Display for Crt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render('#', '.'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Crt;
+    use crate::cpu::Cycle;
+
+    #[test]
+    fn test_beam() {
+        let crt = Crt::default();
+        assert_eq!((0, 0), crt.beam(1));
+        assert_eq!((39, 0), crt.beam(40));
+        assert_eq!((0, 1), crt.beam(41));
+        assert_eq!((0, 0), crt.beam(241));
+    }
+
+    #[test]
+    fn test_draw_small_screen() {
+        let mut crt = Crt::new(3, 2);
+        crt.extend((1..=6).map(|number| Cycle {
+            number,
+            during: 0,
+            after: 0,
+        }));
+        assert_eq!("##.\n##.", crt.to_string());
+        assert_eq!("██ \n██ ", crt.render('█', ' '));
+    }
+}
@@ -0,0 +1,445 @@
+use std::{
+    fmt,
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+
+use crate::{
+    cpu::Cpu,
+    crt::Crt,
+    isa::{Register, Registers},
+    Instruction,
+};
+
+const HELP: &str = "\
+step [N]             run N cycles, 1 by default
+until cycle N        run until cycle N is about to start
+until instruction N  run until instruction N is about to start
+continue             run until a breakpoint triggers or the program ends
+break R OP N         stop when register R, one of x y z, changes so that `R OP N`
+                     holds, OP being one of == != < <= > >=
+delete N             remove breakpoint N
+info                 list the breakpoints
+print                show the registers, the next instruction and the beam
+screen               show what has been drawn so far
+quit
+An empty line steps a single cycle. Every run also stops at breakpoints.
+";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    const ALL: [(&'static str, Comparison); 6] = [
+        ("==", Comparison::Eq),
+        ("!=", Comparison::Ne),
+        ("<=", Comparison::Le),
+        (">=", Comparison::Ge),
+        ("<", Comparison::Lt),
+        (">", Comparison::Gt),
+    ];
+
+    fn holds(self, left: i32, right: i32) -> bool {
+        match self {
+            Comparison::Eq => left == right,
+            Comparison::Ne => left != right,
+            Comparison::Lt => left < right,
+            Comparison::Le => left <= right,
+            Comparison::Gt => left > right,
+            Comparison::Ge => left >= right,
+        }
+    }
+}
+
+/// A condition on the value of a register.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Condition {
+    pub register: Register,
+    pub comparison: Comparison,
+    pub value: i32,
+}
+
+impl Condition {
+    pub fn holds(self, registers: &Registers) -> bool {
+        self.comparison.holds(registers[self.register], self.value)
+    }
+
+    /// Whether a cycle changed the register so that the condition holds, which keeps a
+    /// breakpoint from triggering again on every cycle that leaves the register alone.
+    fn triggered_by(self, before: &Registers, after: &Registers) -> bool {
+        self.holds(after) && !self.holds(before)
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (operator, _) = Comparison::ALL
+            .iter()
+            .find(|(_, comparison)| *comparison == self.comparison)
+            .expect("every comparison has an operator");
+        write!(f, "{} {operator} {}", self.register, self.value)
+    }
+}
+
+impl FromStr for Condition {
+    type Err = String;
+
+    fn from_str(condition: &str) -> Result<Self, String> {
+        let condition = condition.trim();
+        let name_end = condition
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(condition.len());
+        let register = Register::from_name(&condition[..name_end])
+            .ok_or("conditions start with a register, x y or z, like `x > 10`")?;
+        let condition = condition[name_end..].trim_start();
+        let (operator, comparison) = Comparison::ALL
+            .into_iter()
+            .find(|(operator, _)| condition.starts_with(operator))
+            .ok_or("expected one of == != < <= > >= after the register")?;
+        let value = condition[operator.len()..].trim();
+        let value = value.parse().map_err(|e| format!("{value:?}: {e}"))?;
+        Ok(Condition {
+            register,
+            comparison,
+            value,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    Step(usize),
+    UntilCycle(usize),
+    UntilInstruction(usize),
+    Continue,
+    Break(Condition),
+    Delete(usize),
+    Info,
+    Print,
+    Screen,
+    Help,
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, String> {
+        let number = |word: Option<&str>| -> Result<usize, String> {
+            let word = word.ok_or("expected a number")?;
+            word.parse().map_err(|e| format!("{word:?}: {e}"))
+        };
+        let mut words = line.split_whitespace();
+        let command = match words.next() {
+            None => Command::Step(1),
+            Some("step" | "s") => match words.next() {
+                None => Command::Step(1),
+                count => Command::Step(number(count)?),
+            },
+            Some("until" | "u") => match words.next() {
+                Some("cycle") => Command::UntilCycle(number(words.next())?),
+                Some("instruction") => Command::UntilInstruction(number(words.next())?),
+                _ => return Err("expected `until cycle N` or `until instruction N`".to_owned()),
+            },
+            Some("continue" | "c") => Command::Continue,
+            Some("break" | "b") => {
+                let condition = line.trim_start().split_once(char::is_whitespace);
+                return Ok(Command::Break(condition.map_or("", |(_, c)| c).parse()?));
+            }
+            Some("delete" | "d") => Command::Delete(number(words.next())?),
+            Some("info" | "i") => Command::Info,
+            Some("print" | "p") => Command::Print,
+            Some("screen") => Command::Screen,
+            Some("help" | "h") => Command::Help,
+            Some("quit" | "q") => Command::Quit,
+            Some(other) => return Err(format!("unknown command {other:?}, try `help`")),
+        };
+        match words.next() {
+            Some(extra) => Err(format!("unexpected {extra:?}")),
+            None => Ok(command),
+        }
+    }
+}
+
+/// Why a run stopped before it was done.
+enum Stop {
+    Breakpoint(usize),
+    Finished,
+    OutOfBudget,
+}
+
+/// Runs a program under control of the user, drawing on the screen as it goes.
+pub struct Debugger<'a> {
+    cpu: Cpu<'a>,
+    crt: Crt,
+    breakpoints: Vec<Option<Condition>>,
+}
+
+impl<'a> Debugger<'a> {
+    pub fn new(program: &'a [Instruction]) -> Self {
+        Debugger {
+            cpu: Cpu::new(program),
+            crt: Crt::default(),
+            breakpoints: Vec::new(),
+        }
+    }
+
+    pub fn cpu(&self) -> &Cpu<'a> {
+        &self.cpu
+    }
+
+    /// Carries out `command`, returning what to show the user.
+    pub fn execute(&mut self, command: Command) -> String {
+        match command {
+            Command::Step(0) => self.state(),
+            Command::Step(count) => {
+                let mut left = count;
+                self.run(|_| {
+                    left -= 1;
+                    left == 0
+                })
+            }
+            Command::UntilCycle(cycle) if cycle <= self.cpu.elapsed() + 1 => {
+                format!("cycle {cycle} has already been reached\n")
+            }
+            Command::UntilCycle(cycle) => self.run(|cpu| cpu.elapsed() + 1 == cycle),
+            Command::UntilInstruction(pc) => {
+                self.run(|cpu| cpu.pc() == pc && cpu.at_instruction_start())
+            }
+            Command::Continue => self.run(|_| false),
+            Command::Break(condition) => {
+                self.breakpoints.push(Some(condition));
+                format!("breakpoint {}: {condition}\n", self.breakpoints.len())
+            }
+            Command::Delete(number) => match self.breakpoints.get_mut(number.wrapping_sub(1)) {
+                Some(breakpoint @ Some(_)) => {
+                    *breakpoint = None;
+                    format!("deleted breakpoint {number}\n")
+                }
+                _ => format!("there is no breakpoint {number}\n"),
+            },
+            Command::Info => {
+                let breakpoints: String = self
+                    .breakpoints
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, b)| b.map(|b| format!("breakpoint {}: {b}\n", i + 1)))
+                    .collect();
+                if breakpoints.is_empty() {
+                    "no breakpoints\n".to_owned()
+                } else {
+                    breakpoints
+                }
+            }
+            Command::Print => self.state(),
+            Command::Screen => format!("{}\n", self.crt),
+            Command::Help => HELP.to_owned(),
+            Command::Quit => String::new(),
+        }
+    }
+
+    /// Runs cycles until `done` accepts the state after one of them, a breakpoint triggers or
+    /// the program ends.
+    fn run(&mut self, mut done: impl FnMut(&Cpu) -> bool) -> String {
+        let stop = loop {
+            let before = self.cpu.registers();
+            let Some(cycle) = self.cpu.next() else {
+                break Some(if self.cpu.out_of_budget() {
+                    Stop::OutOfBudget
+                } else {
+                    Stop::Finished
+                });
+            };
+            self.crt.draw(&cycle);
+
+            let after = self.cpu.registers();
+            let breakpoint = self
+                .breakpoints
+                .iter()
+                .position(|b| b.is_some_and(|b| b.triggered_by(&before, &after)));
+            if let Some(breakpoint) = breakpoint {
+                break Some(Stop::Breakpoint(breakpoint));
+            }
+            if done(&self.cpu) {
+                break None;
+            }
+        };
+
+        let reason = match stop {
+            Some(Stop::Breakpoint(i)) => format!(
+                "breakpoint {}: {} hit in cycle {}\n",
+                i + 1,
+                self.breakpoints[i].expect("only set breakpoints trigger"),
+                self.cpu.elapsed()
+            ),
+            Some(Stop::Finished) => "the program has finished\n".to_owned(),
+            Some(Stop::OutOfBudget) => format!(
+                "stopped after the budget of {} cycles, the program may loop forever\n",
+                self.cpu.budget()
+            ),
+            None => String::new(),
+        };
+        reason + &self.state()
+    }
+
+    /// The registers, next instruction and beam position as they are at the start of the next
+    /// cycle.
+    fn state(&self) -> String {
+        let registers = self.cpu.registers();
+        let registers = Register::ALL
+            .map(|register| format!("{register} = {}", registers[register]))
+            .join(", ");
+        let next = self.cpu.elapsed() + 1;
+        match self.cpu.current() {
+            Some(instruction) => {
+                let (x, y) = self.crt.beam(next);
+                format!(
+                    "cycle {next}: {registers}, instruction {}: {instruction}, beam at {x},{y}\n",
+                    self.cpu.pc()
+                )
+            }
+            None => format!(
+                "finished after {} cycles: {registers}\n",
+                self.cpu.elapsed()
+            ),
+        }
+    }
+}
+
+/// Reads commands from `input` until it ends or the user quits, writing a prompt before each.
+pub fn session(
+    program: &[Instruction],
+    input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<()> {
+    let mut debugger = Debugger::new(program);
+    write!(output, "{}", debugger.state())?;
+    let mut lines = input.lines();
+    loop {
+        write!(output, "(day10) ")?;
+        output.flush()?;
+        let Some(line) = lines.next().transpose()? else {
+            return writeln!(output);
+        };
+        match line.parse() {
+            Ok(Command::Quit) => return Ok(()),
+            Ok(command) => write!(output, "{}", debugger.execute(command))?,
+            Err(e) => writeln!(output, "{e}")?,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+    use test_case::test_case;
+
+    use super::{session, Command, Comparison, Condition, Debugger};
+    use crate::{isa::Register, Day10};
+
+    #[test_case("", Command::Step(1))]
+    #[test_case("s 5", Command::Step(5))]
+    #[test_case("until cycle 20", Command::UntilCycle(20))]
+    #[test_case("u instruction 3", Command::UntilInstruction(3))]
+    #[test_case("break x >= 10", Command::Break(Condition { register: Register::X, comparison: Comparison::Ge, value: 10 }))]
+    #[test_case("b z<-3", Command::Break(Condition { register: Register::Z, comparison: Comparison::Lt, value: -3 }))]
+    fn test_parse(line: &str, command: Command) {
+        assert_eq!(Ok(command), line.parse());
+    }
+
+    #[test_case("step two")]
+    #[test_case("until 20")]
+    #[test_case("break w > 1")]
+    #[test_case("break xy > 1")]
+    #[test_case("break x ~ 1")]
+    #[test_case("print all")]
+    fn test_parse_error(line: &str) {
+        assert!(line.parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn test_signal_strength_cycles() {
+        let program = Day10.parse(include_str!("../samples/sample.txt")).unwrap();
+        let mut debugger = Debugger::new(&program);
+        assert_eq!(
+            "cycle 20: x = 21, y = 0, z = 0, instruction 10: addx -1, beam at 19,0\n",
+            debugger.execute(Command::UntilCycle(20))
+        );
+        assert_eq!(
+            "cycle 20 has already been reached\n",
+            debugger.execute(Command::UntilCycle(20))
+        );
+        assert_eq!(
+            debugger.execute(Command::Print),
+            debugger.execute(Command::Step(0))
+        );
+        debugger.execute(Command::Step(40));
+        assert_eq!(19, debugger.cpu().x());
+    }
+
+    #[test]
+    fn test_until_instruction_waits_for_it_to_start() {
+        let program = Day10.parse("addx 3\nnoop\naddx 2\njmp 0\n").unwrap();
+        let mut debugger = Debugger::new(&program);
+        assert_eq!(
+            "cycle 4: x = 4, y = 0, z = 0, instruction 2: addx 2, beam at 3,0\n",
+            debugger.execute(Command::UntilInstruction(2))
+        );
+        assert_eq!(
+            "cycle 7: x = 6, y = 0, z = 0, instruction 0: addx 3, beam at 6,0\n",
+            debugger.execute(Command::UntilInstruction(0))
+        );
+
+        // Halfway through instruction 0 is not the start of it
+        let program = Day10.parse("addx 3\nnoop\naddx 2\n").unwrap();
+        let mut debugger = Debugger::new(&program);
+        assert_eq!(
+            "the program has finished\nfinished after 5 cycles: x = 6, y = 0, z = 0\n",
+            debugger.execute(Command::UntilInstruction(0))
+        );
+    }
+
+    #[test]
+    fn test_session() {
+        let program = Day10.parse("noop\naddx 3\naddx -5\n").unwrap();
+        let commands = "break x < 0\nb x > 2\nuntil instruction 2\nc\nbogus\nd 1\ninfo\nc\n";
+        let mut output = Vec::new();
+        session(&program, commands.as_bytes(), &mut output).unwrap();
+        assert_eq!(
+            "\
+cycle 1: x = 1, y = 0, z = 0, instruction 0: noop, beam at 0,0
+(day10) breakpoint 1: x < 0
+(day10) breakpoint 2: x > 2
+(day10) breakpoint 2: x > 2 hit in cycle 3
+cycle 4: x = 4, y = 0, z = 0, instruction 2: addx -5, beam at 3,0
+(day10) breakpoint 1: x < 0 hit in cycle 5
+finished after 5 cycles: x = -1, y = 0, z = 0
+(day10) unknown command \"bogus\", try `help`
+(day10) deleted breakpoint 1
+(day10) breakpoint 2: x > 2
+(day10) the program has finished
+finished after 5 cycles: x = -1, y = 0, z = 0
+(day10) \n",
+            String::from_utf8(output).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_looping_program() {
+        let program = Day10.parse("addx 1\njmp 0\n").unwrap();
+        let mut debugger = Debugger::new(&program);
+        let output = debugger.execute(Command::Continue);
+        assert!(output.starts_with(
+            "stopped after the budget of 1000000 cycles, the program may loop forever\n"
+        ));
+        assert!(debugger.cpu().out_of_budget());
+        assert_eq!(output, debugger.execute(Command::Step(1)));
+    }
+}
@@ -0,0 +1,354 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Register {
+    /// The register the sprite position is read from
+    X,
+    Y,
+    Z,
+}
+
+impl Register {
+    pub const ALL: [Register; 3] = [Register::X, Register::Y, Register::Z];
+
+    pub fn name(self) -> char {
+        match self {
+            Register::X => 'x',
+            Register::Y => 'y',
+            Register::Z => 'z',
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Register> {
+        Register::ALL
+            .into_iter()
+            .find(|register| name.len() == 1 && name.starts_with(register.name()))
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Registers([i32; 3]);
+
+impl Default for Registers {
+    /// Everything starts at 0, except X which starts at 1.
+    fn default() -> Self {
+        Registers([1, 0, 0])
+    }
+}
+
+impl Registers {
+    /// The value of a register, or the number itself.
+    pub fn read(&self, operand: Operand) -> i32 {
+        match operand {
+            Operand::Register(register) => self[register],
+            Operand::Number(value) => value,
+        }
+    }
+}
+
+impl Index<Register> for Registers {
+    type Output = i32;
+
+    fn index(&self, register: Register) -> &i32 {
+        &self.0[register as usize]
+    }
+}
+
+impl IndexMut<Register> for Registers {
+    fn index_mut(&mut self, register: Register) -> &mut i32 {
+        &mut self.0[register as usize]
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    Number(i32),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Register(register) => write!(f, "{register}"),
+            Operand::Number(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// What an instruction accepts in the place of an operand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// A register that is written to
+    Register,
+    /// A register or a number
+    Value,
+    /// The index of an instruction to jump to
+    Target,
+}
+
+impl Kind {
+    pub fn accepts(self, operand: Operand) -> bool {
+        match (self, operand) {
+            (Kind::Register, Operand::Register(_)) | (Kind::Value, _) => true,
+            (Kind::Target, Operand::Number(target)) => target >= 0,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Kind::Register => write!(f, "register"),
+            Kind::Value => write!(f, "value"),
+            Kind::Target => write!(f, "target"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Op {
+    Noop,
+    Addx,
+    Mulx,
+    Set,
+    Add,
+    Mul,
+    Jmp,
+    Jz,
+    Jnz,
+}
+
+impl Op {
+    pub fn opcode(self) -> &'static Opcode {
+        &ISA[self as usize]
+    }
+}
+
+/// How an instruction is written and what it does. The effect is given operands matching
+/// `operands` and returns the instruction to jump to, if any.
+pub struct Opcode {
+    pub op: Op,
+    pub name: &'static str,
+    pub operands: &'static [Kind],
+    pub cycles: usize,
+    effect: fn(&mut Registers, &[Operand]) -> Option<usize>,
+}
+
+fn register(operand: Operand) -> Register {
+    match operand {
+        Operand::Register(register) => register,
+        Operand::Number(_) => unreachable!("operands are checked against their kind"),
+    }
+}
+
+fn target(operand: Operand) -> usize {
+    match operand {
+        Operand::Number(target) => target as usize,
+        Operand::Register(_) => unreachable!("operands are checked against their kind"),
+    }
+}
+
+/// Every instruction the CPU knows, in the order of [`Op`].
+pub const ISA: [Opcode; 9] = [
+    Opcode {
+        op: Op::Noop,
+        name: "noop",
+        operands: &[],
+        cycles: 1,
+        effect: |_, _| None,
+    },
+    Opcode {
+        op: Op::Addx,
+        name: "addx",
+        operands: &[Kind::Value],
+        cycles: 2,
+        effect: |r, o| {
+            r[Register::X] = r[Register::X].wrapping_add(r.read(o[0]));
+            None
+        },
+    },
+    Opcode {
+        op: Op::Mulx,
+        name: "mulx",
+        operands: &[Kind::Value],
+        cycles: 3,
+        effect: |r, o| {
+            r[Register::X] = r[Register::X].wrapping_mul(r.read(o[0]));
+            None
+        },
+    },
+    Opcode {
+        op: Op::Set,
+        name: "set",
+        operands: &[Kind::Register, Kind::Value],
+        cycles: 1,
+        effect: |r, o| {
+            r[register(o[0])] = r.read(o[1]);
+            None
+        },
+    },
+    Opcode {
+        op: Op::Add,
+        name: "add",
+        operands: &[Kind::Register, Kind::Value],
+        cycles: 2,
+        effect: |r, o| {
+            r[register(o[0])] = r.read(o[0]).wrapping_add(r.read(o[1]));
+            None
+        },
+    },
+    Opcode {
+        op: Op::Mul,
+        name: "mul",
+        operands: &[Kind::Register, Kind::Value],
+        cycles: 3,
+        effect: |r, o| {
+            r[register(o[0])] = r.read(o[0]).wrapping_mul(r.read(o[1]));
+            None
+        },
+    },
+    Opcode {
+        op: Op::Jmp,
+        name: "jmp",
+        operands: &[Kind::Target],
+        cycles: 1,
+        effect: |_, o| Some(target(o[0])),
+    },
+    Opcode {
+        op: Op::Jz,
+        name: "jz",
+        operands: &[Kind::Value, Kind::Target],
+        cycles: 2,
+        effect: |r, o| (r.read(o[0]) == 0).then(|| target(o[1])),
+    },
+    Opcode {
+        op: Op::Jnz,
+        name: "jnz",
+        operands: &[Kind::Value, Kind::Target],
+        cycles: 2,
+        effect: |r, o| (r.read(o[0]) != 0).then(|| target(o[1])),
+    },
+];
+
+/// The most operands any instruction takes.
+const MAX_OPERANDS: usize = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    op: Op,
+    /// Padded with zeros past the operands the instruction takes
+    operands: [Operand; MAX_OPERANDS],
+}
+
+impl Instruction {
+    /// Panics unless `operands` are what `op` takes.
+    pub fn new(op: Op, operands: &[Operand]) -> Self {
+        let kinds = op.opcode().operands;
+        assert!(
+            kinds.len() == operands.len() && kinds.iter().zip(operands).all(|(k, &o)| k.accepts(o)),
+            "{} takes {kinds:?}, not {operands:?}",
+            op.opcode().name
+        );
+        let mut padded = [Operand::Number(0); MAX_OPERANDS];
+        padded[..operands.len()].copy_from_slice(operands);
+        Instruction {
+            op,
+            operands: padded,
+        }
+    }
+
+    pub fn noop() -> Self {
+        Instruction::new(Op::Noop, &[])
+    }
+
+    pub fn addx(value: i32) -> Self {
+        Instruction::new(Op::Addx, &[Operand::Number(value)])
+    }
+
+    pub fn op(&self) -> Op {
+        self.op
+    }
+
+    pub fn operands(&self) -> &[Operand] {
+        &self.operands[..self.op.opcode().operands.len()]
+    }
+
+    /// How many cycles the instruction takes to complete.
+    pub fn cycles(&self) -> usize {
+        self.op.opcode().cycles
+    }
+
+    /// Applies the instruction to `registers`, returning the instruction to jump to, if any.
+    pub(crate) fn execute(&self, registers: &mut Registers) -> Option<usize> {
+        (self.op.opcode().effect)(registers, self.operands())
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.op.opcode().name)?;
+        self.operands()
+            .iter()
+            .try_for_each(|operand| write!(f, " {operand}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::{Instruction, Op, Operand, Register, Registers, ISA};
+
+    #[test]
+    fn test_table_order() {
+        assert!(ISA
+            .iter()
+            .enumerate()
+            .all(|(i, opcode)| opcode.op as usize == i));
+    }
+
+    #[test_case(Op::Mulx, &[Operand::Register(Register::X)], [3, 2, 3], None, [9, 2, 3]; "mulx")]
+    #[test_case(Op::Set, &[Operand::Register(Register::Z), Operand::Number(-4)], [1, 2, 3], None, [1, 2, -4]; "set")]
+    #[test_case(Op::Add, &[Operand::Register(Register::Y), Operand::Register(Register::Z)], [1, 2, 3], None, [1, 5, 3]; "add")]
+    #[test_case(Op::Mul, &[Operand::Register(Register::Z), Operand::Number(3)], [1, 2, 3], None, [1, 2, 9]; "mul")]
+    #[test_case(Op::Jmp, &[Operand::Number(7)], [1, 2, 3], Some(7), [1, 2, 3]; "jmp")]
+    #[test_case(Op::Jz, &[Operand::Register(Register::Y), Operand::Number(7)], [1, 0, 3], Some(7), [1, 0, 3]; "jz taken")]
+    #[test_case(Op::Jnz, &[Operand::Register(Register::Y), Operand::Number(7)], [1, 0, 3], None, [1, 0, 3]; "jnz not taken")]
+    fn test_execute(
+        op: Op,
+        operands: &[Operand],
+        before: [i32; 3],
+        jump: Option<usize>,
+        after: [i32; 3],
+    ) {
+        let mut registers = Registers(before);
+        assert_eq!(jump, Instruction::new(op, operands).execute(&mut registers));
+        assert_eq!(Registers(after), registers);
+    }
+
+    #[test]
+    #[should_panic(expected = "jmp takes [Target]")]
+    fn test_rejects_wrong_operands() {
+        Instruction::new(Op::Jmp, &[Operand::Register(Register::X)]);
+    }
+
+    #[test]
+    fn test_display() {
+        let instruction = Instruction::new(
+            Op::Jnz,
+            &[Operand::Register(Register::Y), Operand::Number(3)],
+        );
+        assert_eq!("jnz y 3", instruction.to_string());
+        assert_eq!("addx -5", Instruction::addx(-5).to_string());
+    }
+}
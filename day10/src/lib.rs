@@ -0,0 +1,37 @@
+use aoc_common::{ParseError, Solution};
+
+pub mod asm;
+pub mod cpu;
+pub mod crt;
+pub mod debugger;
+pub mod isa;
+pub mod ocr;
+pub mod part1;
+pub mod part2;
+
+pub use isa::Instruction;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+    type Output1 = i32;
+    type Output2 = Result<String, part2::Unreadable>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        asm::assemble(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> i32 {
+        part1::run(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<String, part2::Unreadable> {
+        part2::run(input)
+    }
+}
+
+// The sample draws stripes rather than letters, so its screen is checked in `part2` instead
+aoc_common::sample_tests!(crate::Day10, sample => part1);
@@ -0,0 +1,143 @@
+use std::fmt;
+
+use crate::crt::Crt;
+
+const GLYPH_WIDTH: usize = 4;
+const GLYPH_HEIGHT: usize = 6;
+/// Every glyph is followed by a dark column
+const CELL_WIDTH: usize = GLYPH_WIDTH + 1;
+
+/// The letters known to appear in puzzle answers, drawn row by row.
+const FONT: [(char, &str); 18] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Y', "#...#....#.#..#...#...#."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The screen is this many pixels high instead of one letter
+    Height(usize),
+    /// A glyph on the screen that doesn't match any letter of the font
+    UnknownGlyph {
+        /// Position of the glyph on the screen, counted from 1
+        position: usize,
+        /// The glyph as drawn, `#` for lit pixels and `.` for dark ones
+        glyph: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Height(height) => write!(
+                f,
+                "the screen is {height} pixels high, but letters are {GLYPH_HEIGHT}"
+            ),
+            Error::UnknownGlyph { position, glyph } => {
+                write!(f, "glyph {position} is not a known letter:")?;
+                for row in glyph.as_bytes().chunks(GLYPH_WIDTH) {
+                    write!(f, "\n{}", String::from_utf8_lossy(row))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Reads the letters drawn on a screen that is one glyph high.
+pub fn read(crt: &Crt) -> Result<String, Error> {
+    let pixels = crt.pixels();
+    if pixels.height() != GLYPH_HEIGHT {
+        return Err(Error::Height(pixels.height()));
+    }
+
+    (0..pixels.width() / CELL_WIDTH)
+        .map(|cell| {
+            let glyph: String = (0..GLYPH_HEIGHT)
+                .flat_map(|y| (0..GLYPH_WIDTH).map(move |x| (cell * CELL_WIDTH + x, y)))
+                .map(|position| if pixels[position] { '#' } else { '.' })
+                .collect();
+            FONT.iter()
+                .find(|(_, drawn)| *drawn == glyph)
+                .map(|&(letter, _)| letter)
+                .ok_or(Error::UnknownGlyph {
+                    position: cell + 1,
+                    glyph,
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::{read, Error};
+    use crate::{cpu::Cycle, crt::Crt, part2, Day10};
+
+    /// Draws `rows` by moving the sprite under the beam for lit pixels and away from it otherwise.
+    fn screen(rows: &[&str]) -> Crt {
+        let mut crt = Crt::new(rows[0].len(), rows.len());
+        let pixels = rows.iter().flat_map(|row| row.chars().enumerate());
+        crt.extend(pixels.enumerate().map(|(i, (x, pixel))| {
+            let during = if pixel == '#' { x as i32 } else { -2 };
+            Cycle {
+                number: i + 1,
+                during,
+                after: during,
+            }
+        }));
+        crt
+    }
+
+    #[test]
+    fn test_read_letters() {
+        let crt = screen(&[
+            "#..#..###.#....",
+            "#..#...#..#....",
+            "####...#..#....",
+            "#..#...#..#....",
+            "#..#...#..#....",
+            "#..#..###.####.",
+        ]);
+        assert_eq!(Ok("HIL".to_owned()), read(&crt));
+    }
+
+    #[test]
+    fn test_unknown_glyph() {
+        let program = Day10.parse(include_str!("../samples/sample.txt")).unwrap();
+        let error = read(&part2::draw(&program, Crt::default())).unwrap_err();
+        assert_eq!(
+            Error::UnknownGlyph {
+                position: 1,
+                glyph: "##..###.################".to_owned(),
+            },
+            error
+        );
+        assert_eq!(
+            "glyph 1 is not a known letter:\n##..\n###.\n####\n####\n####\n####",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_wrong_height() {
+        assert_eq!(Err(Error::Height(8)), read(&Crt::new(40, 8)));
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{cpu::Cpu, Instruction};
+
+/// The last cycle whose signal strength counts.
+const LAST_CYCLE: usize = 220;
+
+pub fn run(instructions: &[Instruction]) -> i32 {
+    Cpu::new(instructions)
+        .take(LAST_CYCLE)
+        .filter(|cycle| cycle.number % 40 == 20)
+        .map(|cycle| cycle.number as i32 * cycle.during)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+    use crate::asm::assemble;
+
+    #[test]
+    fn test_looping_program() {
+        // X climbs by 1 every 3 cycles, and is 7 during cycle 20
+        let program = assemble("addx 1\njmp 0\n").unwrap();
+        let strengths: i32 = [20, 60, 100, 140, 180, 220]
+            .map(|cycle: i32| cycle * (1 + cycle / 3))
+            .iter()
+            .sum();
+        assert_eq!(strengths, run(&program));
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt;
+
+use crate::{cpu::Cpu, crt::Crt, ocr, Instruction};
+
+/// Runs the program, drawing on `crt` on every cycle until the screen is full.
+pub fn draw(instructions: &[Instruction], mut crt: Crt) -> Crt {
+    crt.extend(Cpu::new(instructions));
+    crt
+}
+
+/// A screen that doesn't show letters, along with what went wrong reading it.
+#[derive(Debug)]
+pub struct Unreadable {
+    pub error: ocr::Error,
+    pub screen: Crt,
+}
+
+impl fmt::Display for Unreadable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.error)?;
+        write!(f, "on the screen:\n{}", self.screen)
+    }
+}
+
+/// The letters on the screen.
+pub fn run(instructions: &[Instruction]) -> Result<String, Unreadable> {
+    let screen = draw(instructions, Crt::default());
+    ocr::read(&screen).map_err(|error| Unreadable { error, screen })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::{draw, run};
+    use crate::{crt::Crt, Day10};
+
+    #[test]
+    fn test_sample_screen() {
+        let program = Day10.parse(include_str!("../samples/sample.txt")).unwrap();
+        let screen = draw(&program, Crt::default()).render('█', ' ');
+        assert_eq!(
+            "\
+██  ██  ██  ██  ██  ██  ██  ██  ██  ██  
+███   ███   ███   ███   ███   ███   ███ 
+████    ████    ████    ████    ████    
+█████     █████     █████     █████     
+██████      ██████      ██████      ████
+███████       ███████       ███████     ",
+            screen
+        );
+    }
+
+    #[test]
+    fn test_sample_is_not_letters() {
+        let program = Day10.parse(include_str!("../samples/sample.txt")).unwrap();
+        let error = run(&program).unwrap_err().to_string();
+        assert!(error.starts_with("glyph 1 is not a known letter:\n##..\n"));
+        assert!(error.ends_with("on the screen:\n##..##..##..##..##..##..##..##..##..##..\n###...###...###...###...###...###...###.\n####....####....####....####....####....\n#####.....#####.....#####.....#####.....\n######......######......######......####\n#######.......#######.......#######....."));
+    }
+
+    #[test]
+    fn test_looping_program() {
+        let program = Day10.parse("addx 1\njmp 0\n").unwrap();
+        let screen = draw(&program, Crt::default()).to_string();
+        assert_eq!(
+            Some("####...................................."),
+            screen.lines().next()
+        );
+        assert_eq!(6, screen.lines().count());
+    }
+}
@@ -0,0 +1,218 @@
+use aoc_common::{parse_field, ParseError, Solution};
+use std::collections::VecDeque;
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Clone, Debug)]
+pub enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+impl Operation {
+    fn act(&self, item: Item) -> Item {
+        match self {
+            Self::Add(m) => Item(item.0 + m),
+            Self::Multiply(m) => Item(item.0 * m),
+            Self::Square => Item(item.0 * item.0),
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Item(u64);
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    items: VecDeque<Item>,
+    operation: Operation,
+    test: u64,
+    false_target: usize,
+    true_target: usize,
+    actions_taken: u128,
+}
+
+fn parse_operation(input: &str, operation: &str) -> Result<Operation, ParseError> {
+    let (operator, operand) = operation.split_once(' ').ok_or_else(|| {
+        ParseError::new(
+            Day11::DAY,
+            input,
+            operation,
+            "expected operator and operand",
+        )
+    })?;
+
+    match (operator, operand) {
+        ("*", "old") => Ok(Operation::Square),
+        ("+", "old") => Ok(Operation::Multiply(2)),
+        ("*", operand) => Ok(Operation::Multiply(parse_field(
+            Day11::DAY,
+            input,
+            operand,
+        )?)),
+        ("+", operand) => Ok(Operation::Add(parse_field(Day11::DAY, input, operand)?)),
+        _ => Err(ParseError::new(
+            Day11::DAY,
+            input,
+            operator,
+            "expected + or *",
+        )),
+    }
+}
+
+fn parse_line<'a>(
+    input: &str,
+    block: &'a str,
+    lines: &mut impl Iterator<Item = &'a str>,
+    prefix: &str,
+) -> Result<&'a str, ParseError> {
+    let line = lines.next().ok_or_else(|| {
+        ParseError::new(
+            Day11::DAY,
+            input,
+            &block[block.len()..],
+            format!("expected \"{prefix}\""),
+        )
+    })?;
+    line.strip_prefix(prefix)
+        .map(str::trim)
+        .ok_or_else(|| ParseError::new(Day11::DAY, input, line, format!("expected \"{prefix}\"")))
+}
+
+/// Parses the monkey at `index` of `count`, which may only throw to the other monkeys.
+fn parse_monkey(
+    input: &str,
+    block: &str,
+    index: usize,
+    count: usize,
+) -> Result<Monkey, ParseError> {
+    let mut lines = block.lines().skip(1).map(str::trim);
+    let items: VecDeque<Item> = parse_line(input, block, &mut lines, "Starting items:")?
+        .split(", ")
+        .map(|i| parse_field(Day11::DAY, input, i).map(Item))
+        .collect::<Result<_, _>>()?;
+
+    let operation = parse_operation(
+        input,
+        parse_line(input, block, &mut lines, "Operation: new = old")?,
+    )?;
+
+    let test = parse_line(input, block, &mut lines, "Test: divisible by")?;
+    let test = match parse_field(Day11::DAY, input, test)? {
+        0 => Err(ParseError::new(
+            Day11::DAY,
+            input,
+            test,
+            "expected a divisor above 0",
+        )),
+        divisor => Ok(divisor),
+    }?;
+
+    let mut target = |prefix| {
+        let target = parse_line(input, block, &mut lines, prefix)?;
+        match parse_field(Day11::DAY, input, target)? {
+            to if to == index => Err(ParseError::new(
+                Day11::DAY,
+                input,
+                target,
+                "a monkey can't throw to itself",
+            )),
+            to if to >= count => Err(ParseError::new(
+                Day11::DAY,
+                input,
+                target,
+                format!("expected a monkey below {count}"),
+            )),
+            to => Ok(to),
+        }
+    };
+    let true_target = target("If true: throw to monkey")?;
+    let false_target = target("If false: throw to monkey")?;
+
+    Ok(Monkey {
+        items,
+        operation,
+        test,
+        true_target,
+        false_target,
+        actions_taken: 0,
+    })
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+    type Output1 = u128;
+    type Output2 = u128;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let blocks: Vec<_> = aoc_common::blocks(input).collect();
+        blocks
+            .iter()
+            .enumerate()
+            .map(|(index, block)| parse_monkey(input, block, index, blocks.len()))
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> u128 {
+        part1::run(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> u128 {
+        part2::run(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+    use test_case::test_case;
+
+    use crate::{Day11, Item};
+
+    #[test]
+    fn test_parse_sample() {
+        let input = include_str!("../samples/sample.txt");
+        let monkeys = Day11.parse(input).unwrap();
+
+        let monkey_one = &monkeys[0];
+        assert!(monkey_one.items == [Item(79), Item(98)]);
+        assert_eq!(23, monkey_one.test);
+        assert_eq!(19, monkey_one.operation.act(Item(1)).0);
+        assert_eq!(3, monkey_one.false_target);
+        assert_eq!(2, monkey_one.true_target);
+    }
+
+    #[test]
+    fn test_parse_unknown_operator() {
+        let input = include_str!("../samples/sample.txt").replacen("old + 6", "old - 6", 1);
+        let error = Day11.parse(&input).unwrap_err();
+        assert_eq!((10, 24), (error.line(), error.column()));
+        assert_eq!("-", error.text());
+    }
+
+    #[test]
+    fn test_parse_missing_field() {
+        let input = "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old * 19\n";
+        let error = Day11.parse(input).unwrap_err();
+        assert_eq!(3, error.line());
+        assert!(error.to_string().contains("Test: divisible by"));
+    }
+
+    #[test_case("divisible by 23", "divisible by 0", 4, 22, "expected a divisor above 0"; "zero divisor")]
+    #[test_case("monkey 2", "monkey 4", 5, 30, "expected a monkey below 4"; "target out of range")]
+    #[test_case("monkey 3", "monkey 0", 6, 31, "a monkey can't throw to itself"; "throws to itself")]
+    fn test_parse_invalid_monkey(from: &str, to: &str, line: usize, column: usize, message: &str) {
+        let input = include_str!("../samples/sample.txt").replacen(from, to, 1);
+        let error = Day11.parse(&input).unwrap_err();
+        assert_eq!((line, column), (error.line(), error.column()));
+        assert!(error.to_string().contains(message));
+    }
+}
+
+aoc_common::sample_tests!(crate::Day11);
@@ -0,0 +1,34 @@
+use std::{cell::RefCell, cmp::Reverse};
+
+use crate::{Item, Monkey};
+
+fn monkey_business(mut monkeys: Vec<RefCell<Monkey>>) -> u128 {
+    for _ in 0..20 {
+        for monkey in &monkeys {
+            let mut monkey = monkey.borrow_mut();
+            while let Some(i) = monkey.items.pop_front() {
+                let mut worry = monkey.operation.act(i);
+                worry = Item(worry.0 / 3);
+                let target = if worry.0 % monkey.test == 0 {
+                    &monkeys[monkey.true_target]
+                } else {
+                    &monkeys[monkey.false_target]
+                };
+                target.borrow_mut().items.push_back(worry);
+                monkey.actions_taken += 1;
+            }
+        }
+    }
+
+    monkeys.sort_by_key(|m| Reverse(m.borrow().actions_taken));
+
+    monkeys[..=1]
+        .iter()
+        .map(|m| m.borrow().actions_taken)
+        .product()
+}
+
+pub fn run(monkeys: &[Monkey]) -> u128 {
+    let monkeys = monkeys.iter().cloned().map(RefCell::new).collect();
+    monkey_business(monkeys)
+}
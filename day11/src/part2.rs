@@ -0,0 +1,35 @@
+use std::{cell::RefCell, cmp::Reverse};
+
+use crate::{Item, Monkey};
+
+fn monkey_business(mut monkeys: Vec<RefCell<Monkey>>, total_test: u64) -> u128 {
+    for _ in 0..10000 {
+        for monkey in &monkeys {
+            let mut monkey = monkey.borrow_mut();
+            while let Some(i) = monkey.items.pop_front() {
+                let worry = monkey.operation.act(i);
+                let target = if worry.0 % monkey.test == 0 {
+                    &monkeys[monkey.true_target]
+                } else {
+                    &monkeys[monkey.false_target]
+                };
+                let worry = Item(worry.0 % total_test);
+                target.borrow_mut().items.push_back(worry);
+                monkey.actions_taken += 1;
+            }
+        }
+    }
+
+    monkeys.sort_by_key(|m| Reverse(m.borrow().actions_taken));
+
+    monkeys[..=1]
+        .iter()
+        .map(|m| m.borrow().actions_taken)
+        .product()
+}
+
+pub fn run(monkeys: &[Monkey]) -> u128 {
+    let total_test: u64 = monkeys.iter().map(|c| c.test).product();
+    let monkeys = monkeys.iter().cloned().map(RefCell::new).collect();
+    monkey_business(monkeys, total_test)
+}
@@ -0,0 +1,203 @@
+use std::fmt;
+
+use aoc_common::{Compass, Grid, ParseError, Point, Solution};
+
+pub mod part1;
+pub mod part2;
+pub mod render;
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum MapPoint {
+    Altitude(u8),
+    Start,
+    End,
+}
+
+impl From<&MapPoint> for i32 {
+    fn from(val: &MapPoint) -> Self {
+        match val {
+            MapPoint::Start => 0,
+            MapPoint::End => 25,
+            MapPoint::Altitude(a) => *a as i32,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Only up, down, left and right
+    Four,
+    /// Diagonally as well
+    Eight,
+}
+
+/// How the hill may be walked. The default is the puzzle's: climbing at most one step of
+/// elevation at a time, descending any amount, never diagonally, with every step costing 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClimbRule {
+    pub max_ascent: u8,
+    /// The steepest drop allowed, if any drop isn't
+    pub max_descent: Option<u8>,
+    pub connectivity: Connectivity,
+    /// What every unit of elevation gained or lost adds to the cost of a step
+    pub elevation_cost: usize,
+}
+
+impl Default for ClimbRule {
+    fn default() -> Self {
+        ClimbRule {
+            max_ascent: 1,
+            max_descent: None,
+            connectivity: Connectivity::Four,
+            elevation_cost: 0,
+        }
+    }
+}
+
+impl ClimbRule {
+    fn allows(&self, from: &MapPoint, to: &MapPoint) -> bool {
+        let change = i32::from(to) - i32::from(from);
+        change <= i32::from(self.max_ascent)
+            && self
+                .max_descent
+                .is_none_or(|max_descent| -change <= i32::from(max_descent))
+    }
+
+    fn step_cost(&self, from: &MapPoint, to: &MapPoint) -> usize {
+        1 + self.elevation_cost * i32::from(from).abs_diff(i32::from(to)) as usize
+    }
+
+    fn offsets(&self) -> impl Iterator<Item = Point> {
+        let directions: &[Compass] = match self.connectivity {
+            Connectivity::Four => &[Compass::North, Compass::East, Compass::South, Compass::West],
+            Connectivity::Eight => &Compass::ALL,
+        };
+        directions.iter().map(|d| d.offset())
+    }
+
+    /// A lower bound on the cost of getting from `from` to `to`.
+    fn estimate(&self, from: Point, to: Point) -> usize {
+        match self.connectivity {
+            Connectivity::Four => from.manhattan(to) as usize,
+            Connectivity::Eight => from.chebyshev(to) as usize,
+        }
+    }
+
+    /// Where `from` can walk to in one step, with what that step costs.
+    fn neighbours<'a>(
+        &'a self,
+        map: &'a Grid<MapPoint>,
+        from: Point,
+    ) -> impl Iterator<Item = (Point, usize)> + 'a {
+        let current = &map[from];
+        self.offsets().filter_map(move |offset| {
+            let next = from + offset;
+            let to = map.at(next)?;
+            self.allows(current, to)
+                .then(|| (next, self.step_cost(current, to)))
+        })
+    }
+
+    /// The neighbours that can walk to `to`, for searching backwards from the end.
+    fn reverse_neighbours<'a>(
+        &'a self,
+        map: &'a Grid<MapPoint>,
+        to: Point,
+    ) -> impl Iterator<Item = (Point, usize)> + 'a {
+        let current = &map[to];
+        self.offsets().filter_map(move |offset| {
+            let previous = to + offset;
+            let from = map.at(previous)?;
+            self.allows(from, current)
+                .then(|| (previous, self.step_cost(from, current)))
+        })
+    }
+}
+
+/// Parses the hill, which must have exactly one start, `S`, and one end, `E`.
+pub fn parse_input(input: &str) -> Result<Grid<MapPoint>, ParseError> {
+    let map = Grid::parse(Day12::DAY, input, |c| match c {
+        'S' => Ok(MapPoint::Start),
+        'E' => Ok(MapPoint::End),
+        'a'..='z' => Ok(MapPoint::Altitude(c as u8 - 97)),
+        _ => Err("expected S, E or a-z"),
+    })?;
+
+    for (marker, name) in [('S', "start"), ('E', "end")] {
+        let mut found = input.match_indices(marker);
+        if found.next().is_none() {
+            return Err(ParseError::new(
+                Day12::DAY,
+                input,
+                &input[input.len()..],
+                format!("expected the {name}, {marker}"),
+            ));
+        }
+        if let Some((at, extra)) = found.next() {
+            return Err(ParseError::new(
+                Day12::DAY,
+                input,
+                &input[at..at + extra.len()],
+                format!("expected only one {name}"),
+            ));
+        }
+    }
+    Ok(map)
+}
+
+/// The end can't be reached from any start under the climbing rule in use.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NoRoute;
+
+impl fmt::Display for NoRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no route under these climbing rules")
+    }
+}
+
+#[derive(Default)]
+pub struct Day12 {
+    pub rule: ClimbRule,
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Grid<MapPoint>;
+    type Output1 = Result<usize, NoRoute>;
+    type Output2 = Result<usize, NoRoute>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<usize, NoRoute> {
+        part1::run(input, &self.rule)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<usize, NoRoute> {
+        part2::run(input, &self.rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::parse_input;
+
+    #[test_case("abc\nabE\n", 3, 1, "expected the start, S"; "no start")]
+    #[test_case("Sbc\nabd\n", 3, 1, "expected the end, E"; "no end")]
+    #[test_case("SbE\nSbd\n", 2, 1, "expected only one start at \"S\""; "two starts")]
+    #[test_case("SbE\naEd\n", 2, 2, "expected only one end at \"E\""; "two ends")]
+    fn test_parse_markers(input: &str, line: usize, column: usize, message: &str) {
+        let error = parse_input(input).unwrap_err();
+        assert_eq!((line, column), (error.line(), error.column()));
+        assert_eq!(
+            format!("day 12, line {line}, column {column}: {message}"),
+            error.to_string()
+        );
+    }
+}
+
+aoc_common::sample_tests!(crate::Day12::default());
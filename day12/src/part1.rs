@@ -0,0 +1,85 @@
+use aoc_common::{
+    search::{self, Path},
+    Grid, Point,
+};
+
+use crate::{ClimbRule, MapPoint, NoRoute};
+
+/// Panics unless the map has a start and an end, which [`crate::parse_input`] ensures.
+fn find_start_and_end(map: &Grid<MapPoint>) -> (Point, Point) {
+    let find = |marker: MapPoint| {
+        map.position(|p| *p == marker)
+            .expect("parsing checks for the start and the end")
+    };
+    (
+        Point::from(find(MapPoint::Start)),
+        Point::from(find(MapPoint::End)),
+    )
+}
+
+/// The cheapest path from the start to the end, if the rule allows any.
+pub fn find_path(map: &Grid<MapPoint>, rule: &ClimbRule) -> Option<Path<Point, usize>> {
+    let (start, end) = find_start_and_end(map);
+
+    search::astar(
+        [start],
+        |&p| rule.neighbours(map, p),
+        |&p| rule.estimate(p, end),
+        |&p| p == end,
+    )
+}
+
+/// The positions of a cheapest route, from the start up to and including the end.
+pub fn shortest_route(map: &Grid<MapPoint>, rule: &ClimbRule) -> Option<Vec<Point>> {
+    find_path(map, rule).map(|path| path.nodes)
+}
+
+pub fn run(map: &Grid<MapPoint>, rule: &ClimbRule) -> Result<usize, NoRoute> {
+    find_path(map, rule).map(|path| path.cost).ok_or(NoRoute)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+    use test_case::test_case;
+
+    use super::run;
+    use crate::{ClimbRule, Connectivity, Day12, NoRoute};
+
+    #[test_case(1, None, Connectivity::Four, 0, 31)]
+    #[test_case(25, None, Connectivity::Four, 0, 7)]
+    #[test_case(25, None, Connectivity::Eight, 0, 5)]
+    #[test_case(1, None, Connectivity::Eight, 0, 27)]
+    #[test_case(1, Some(0), Connectivity::Four, 0, 31)]
+    #[test_case(1, None, Connectivity::Four, 1, 56)]
+    fn test_climb_rules(
+        max_ascent: u8,
+        max_descent: Option<u8>,
+        connectivity: Connectivity,
+        elevation_cost: usize,
+        cost: usize,
+    ) {
+        let map = Day12::default()
+            .parse(include_str!("../samples/sample.txt"))
+            .unwrap();
+        let rule = ClimbRule {
+            max_ascent,
+            max_descent,
+            connectivity,
+            elevation_cost,
+        };
+        assert_eq!(Ok(cost), run(&map, &rule));
+    }
+
+    #[test]
+    fn test_no_route() {
+        let map = Day12::default()
+            .parse(include_str!("../samples/sample.txt"))
+            .unwrap();
+        let rule = ClimbRule {
+            max_ascent: 0,
+            ..ClimbRule::default()
+        };
+        assert_eq!(Err(NoRoute), run(&map, &rule));
+    }
+}
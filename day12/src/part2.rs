@@ -0,0 +1,85 @@
+use aoc_common::{search, Grid, Point};
+
+use crate::{ClimbRule, MapPoint, NoRoute};
+
+/// The result of walking back from the end to every position that can reach it.
+pub struct Descent {
+    /// The cheapest cost from each position to the end, if it can get there at all
+    pub distances: Grid<Option<usize>>,
+    /// The lowest position that is cheapest to reach the end from, unless none can reach it
+    pub start: Option<Point>,
+    /// The route from `start` up to and including the end, empty without a start
+    pub path: Vec<Point>,
+}
+
+pub fn descend(map: &Grid<MapPoint>, rule: &ClimbRule) -> Descent {
+    let end = map
+        .position(|p| *p == MapPoint::End)
+        .expect("parsing checks for the end");
+    let reachable =
+        search::dijkstra_reachable([Point::from(end)], |&p| rule.reverse_neighbours(map, p));
+
+    let start = reachable
+        .iter()
+        .filter(|&(&p, _)| i32::from(&map[p]) == 0)
+        .min_by_key(|&(_, cost)| cost)
+        .map(|(&start, _)| start);
+    let mut path = start.map_or_else(Vec::new, |start| {
+        reachable
+            .path_to(&start)
+            .expect("the start was reached")
+            .nodes
+    });
+    path.reverse();
+
+    Descent {
+        distances: Grid::from_fn(map.width(), map.height(), |x, y| {
+            reachable.get(&Point::from((x, y)))
+        }),
+        start,
+        path,
+    }
+}
+
+pub fn run(map: &Grid<MapPoint>, rule: &ClimbRule) -> Result<usize, NoRoute> {
+    let descent = descend(map, rule);
+    let start = descent.start.ok_or(NoRoute)?;
+    Ok(descent.distances[start].expect("the start was reached"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Point, Solution};
+
+    use super::{descend, run};
+    use crate::{ClimbRule, Day12, NoRoute};
+
+    #[test]
+    fn test_descend() {
+        let map = Day12::default()
+            .parse(include_str!("../samples/sample.txt"))
+            .unwrap();
+        let descent = descend(&map, &ClimbRule::default());
+
+        assert_eq!(Some(Point::new(0, 4)), descent.start);
+        assert_eq!(30, descent.path.len());
+        assert_eq!(Some(&Point::new(5, 2)), descent.path.last());
+        assert_eq!(Some(31), descent.distances[(0, 0)]);
+        assert_eq!(Some(0), descent.distances[(5, 2)]);
+    }
+
+    #[test]
+    fn test_no_route() {
+        let map = Day12::default()
+            .parse(include_str!("../samples/sample.txt"))
+            .unwrap();
+        let rule = ClimbRule {
+            max_ascent: 0,
+            ..ClimbRule::default()
+        };
+        let descent = descend(&map, &rule);
+        assert_eq!((None, 0), (descent.start, descent.path.len()));
+        assert_eq!(Some(0), descent.distances[(5, 2)]);
+        assert_eq!(Err(NoRoute), run(&map, &rule));
+    }
+}
@@ -0,0 +1,126 @@
+use aoc_common::{image::Rgb, Compass, Grid, Point};
+
+use crate::MapPoint;
+
+/// What a cell of the drawn height map shows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    /// A position off the route, at an elevation from 0 (`a`) to 25 (`z`)
+    Height(u8),
+    /// A step of the route, towards the next position
+    Step(Compass),
+    End,
+}
+
+impl Tile {
+    pub fn glyph(self) -> char {
+        match self {
+            Tile::Height(elevation) => (b'a' + elevation) as char,
+            Tile::Step(Compass::North) => '^',
+            Tile::Step(Compass::NorthEast) => '↗',
+            Tile::Step(Compass::East) => '>',
+            Tile::Step(Compass::SouthEast) => '↘',
+            Tile::Step(Compass::South) => 'v',
+            Tile::Step(Compass::SouthWest) => '↙',
+            Tile::Step(Compass::West) => '<',
+            Tile::Step(Compass::NorthWest) => '↖',
+            Tile::End => 'E',
+        }
+    }
+
+    pub fn colour(self) -> Rgb {
+        match self {
+            Tile::Height(elevation) => {
+                Rgb(40 + elevation * 7, 90 + elevation * 5, 40 + elevation * 4)
+            }
+            Tile::Step(_) => Rgb(240, 70, 50),
+            Tile::End => Rgb(255, 230, 40),
+        }
+    }
+}
+
+/// The height map with `route` drawn over it as arrows, like in the puzzle statement, with
+/// diagonal arrows for routes that may step diagonally.
+pub fn route_map(map: &Grid<MapPoint>, route: &[Point]) -> Grid<Tile> {
+    let mut tiles = map.map(|point| Tile::Height(i32::from(point) as u8));
+    for step in route.windows(2) {
+        let direction = Compass::ALL
+            .into_iter()
+            .find(|d| step[0] + d.offset() == step[1])
+            .expect("the route only takes single steps");
+        tiles[step[0]] = Tile::Step(direction);
+    }
+    if let Some(&end) = route.last() {
+        tiles[end] = Tile::End;
+    }
+    tiles
+}
+
+/// Colours every position from red when it is close to the end to blue when it is furthest
+/// away, and positions that can't reach the end at all dark grey.
+pub fn heat(distances: &Grid<Option<usize>>) -> Grid<Rgb> {
+    let furthest = distances.iter().filter_map(|(_, d)| *d).max().unwrap_or(0);
+    distances.map(|distance| match distance {
+        Some(distance) => {
+            let warmth = 255 - (distance * 255 / furthest.max(1)) as u8;
+            Rgb(warmth, 40, 255 - warmth)
+        }
+        None => Rgb(40, 40, 40),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{image::Rgb, Solution};
+
+    use super::{heat, route_map, Tile};
+    use crate::{part1, part2, ClimbRule, Connectivity, Day12};
+
+    #[test]
+    fn test_route_map() {
+        let map = Day12::default()
+            .parse(include_str!("../samples/sample.txt"))
+            .unwrap();
+        let route = part1::shortest_route(&map, &ClimbRule::default()).unwrap();
+        let drawing = route_map(&map, &route).render(|_, &tile| match tile {
+            Tile::Height(_) => '.',
+            _ => tile.glyph(),
+        });
+
+        // As short as the route in the puzzle statement, which makes its first turn elsewhere
+        assert_eq!(32, route.len());
+        assert_eq!(">>vv<<<<\n..vvv<<^\n..vv>E^^\n..v>>>^^\n..>>>>>^", drawing);
+    }
+
+    #[test]
+    fn test_diagonal_route_map() {
+        let map = Day12::default()
+            .parse(include_str!("../samples/sample.txt"))
+            .unwrap();
+        let rule = ClimbRule {
+            connectivity: Connectivity::Eight,
+            ..ClimbRule::default()
+        };
+        let route = part1::shortest_route(&map, &rule).unwrap();
+        let tiles = route_map(&map, &route);
+        assert!(route
+            .windows(2)
+            .any(|step| step[0].x != step[1].x && step[0].y != step[1].y));
+        assert!(route[..route.len() - 1]
+            .iter()
+            .all(|&p| matches!(tiles[p], Tile::Step(_))));
+        assert!(tiles
+            .render(|_, tile| tile.glyph())
+            .contains(['↗', '↘', '↙', '↖']));
+    }
+
+    #[test]
+    fn test_heat() {
+        let map = Day12::default()
+            .parse(include_str!("../samples/sample.txt"))
+            .unwrap();
+        let heat = heat(&part2::descend(&map, &ClimbRule::default()).distances);
+        assert_eq!(Rgb(255, 40, 0), heat[(5, 2)]);
+        assert_eq!(Rgb(0, 40, 255), heat[(0, 0)]);
+    }
+}
@@ -1,44 +1,24 @@
-use element::Element;
-use parser::parse_input;
-
-mod element;
-mod parser;
-
-fn run(mut pairs: Vec<Vec<Element>>) -> usize {
-    let p1 = vec![Element::Array(vec![Element::Digit(2)])];
-    let p2 = vec![Element::Array(vec![Element::Digit(6)])];
-
-    pairs.push(vec![Element::Array(vec![Element::Digit(2)])]);
-    pairs.push(vec![Element::Array(vec![Element::Digit(6)])]);
-    pairs.sort();
-
-    let mut p1_pos: Option<usize> = None;
-    let mut p2_pos: Option<usize> = None;
-    for (i, packet) in pairs.into_iter().enumerate() {
-        if packet == p1 {
-            p1_pos = Some(i + 1)
-        } else if packet == p2 {
-            p2_pos = Some(i + 1)
-        }
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Element {
+    Digit(i32),
+    Array(Vec<Element>),
+}
 
-        if p1_pos.is_some() && p2_pos.is_some() {
-            break;
+impl Ord for Element {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Element::Digit(l), Element::Digit(r)) => l.cmp(r),
+            (Element::Digit(l), Element::Array(r)) => vec![Element::Digit(*l)].cmp(r),
+            (Element::Array(l), Element::Digit(r)) => l.cmp(&vec![Element::Digit(*r)]),
+            (Element::Array(l), Element::Array(r)) => l.cmp(r),
         }
     }
-
-    let (Some(p1_pos), Some(p2_pos)) = (p1_pos, p2_pos) else {
-        panic!("Didn't find indices for packets");
-    };
-
-    p1_pos * p2_pos
 }
 
-fn main() {
-    let input = include_str!("../../input.txt").trim();
-    let input = parse_input(input);
-    let res = run(input);
-
-    println!("{res}")
+impl PartialOrd for Element {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 #[cfg(test)]
@@ -47,11 +27,7 @@ mod tests {
 
     use test_case::test_case;
 
-    use crate::{
-        element::Element,
-        parser::{parse_array, parse_input},
-        run,
-    };
+    use crate::{element::Element, parser::parse_array};
 
     #[test]
     fn test_pair_1() {
@@ -124,15 +100,6 @@ mod tests {
         assert_eq!(left.cmp(&right), Ordering::Greater);
     }
 
-    #[test]
-    fn test_sample() {
-        let input = include_str!("../sample.txt");
-        let input = parse_input(input);
-        let res = run(input);
-
-        assert_eq!(140, res);
-    }
-
     #[test_case("[[4],3]", "[[5],2]", true)]
     #[test_case("[[1],[2,3,4]]", "[[1],4]", true)]
     #[test_case("[[[]]]", "[[]]", false)]
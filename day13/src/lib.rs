@@ -0,0 +1,32 @@
+use aoc_common::{ParseError, Solution};
+
+pub use element::Element;
+
+mod element;
+mod parser;
+pub mod part1;
+pub mod part2;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Vec<Element>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parser::parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> usize {
+        part1::run(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> usize {
+        part2::run(input)
+    }
+}
+
+aoc_common::sample_tests!(crate::Day13);
@@ -2,16 +2,18 @@ use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::digit1,
-    combinator::{all_consuming, map},
+    combinator::{all_consuming, map, map_res},
     multi::separated_list0,
     sequence::delimited,
     Finish, IResult,
 };
 
-use crate::element::Element;
+use aoc_common::{ParseError, Solution};
+
+use crate::{element::Element, Day13};
 
 fn parse_digit(input: &str) -> IResult<&str, Element> {
-    map(digit1, |c: &str| Element::Digit(c.parse().unwrap()))(input)
+    map_res(digit1, |c: &str| c.parse().map(Element::Digit))(input)
 }
 
 fn parse_non_empty_array(input: &str) -> IResult<&str, Element> {
@@ -22,7 +24,7 @@ fn parse_non_empty_array(input: &str) -> IResult<&str, Element> {
         separated_list0(alt((tag(", "), tag(","))), array_contents),
         tag("]"),
     );
-    map(non_empty_array, |c| Element::Array(c))(input)
+    map(non_empty_array, Element::Array)(input)
 }
 
 pub fn parse_array(input: &str) -> IResult<&str, Vec<Element>> {
@@ -33,19 +35,41 @@ pub fn parse_array(input: &str) -> IResult<&str, Vec<Element>> {
         _ => panic!("Wrong element type"),
     })
 }
-pub fn parse_input(input: &str) -> Vec<Vec<Element>> {
+pub fn parse_input(input: &str) -> Result<Vec<Vec<Element>>, ParseError> {
     let mut pairs = Vec::new();
     for packet in input.lines().filter(|l| !l.trim().is_empty()) {
-        let p = all_consuming(parse_array)(packet).finish().unwrap().1;
+        let p = all_consuming(parse_array)(packet.trim_end())
+            .finish()
+            .map_err(|e| {
+                let span = e
+                    .input
+                    .chars()
+                    .next()
+                    .map_or(e.input, |c| &e.input[..c.len_utf8()]);
+                ParseError::new(Day13::DAY, input, span, "invalid packet")
+            })?
+            .1;
         pairs.push(p)
     }
 
-    pairs
+    Ok(pairs)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{element::Element, parser::parse_array};
+    use aoc_common::Solution;
+
+    use crate::{element::Element, parser::parse_array, Day13};
+
+    #[test]
+    fn test_parse_unbalanced_packet() {
+        let error = Day13.parse("[1,[2]]\n[1,[2,3]\n").unwrap_err();
+        assert_eq!((2, 9), (error.line(), error.column()));
+
+        let error = Day13.parse("[1,[2]]\n[1,x]\n").unwrap_err();
+        assert_eq!((2, 3), (error.line(), error.column()));
+        assert_eq!(",", error.text());
+    }
 
     #[test]
     fn test_parse_single_digit() {
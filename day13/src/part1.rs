@@ -0,0 +1,17 @@
+use std::cmp::Ordering;
+
+use crate::element::Element;
+
+pub fn run(packets: &[Vec<Element>]) -> usize {
+    packets
+        .chunks_exact(2)
+        .enumerate()
+        .filter_map(|(i, p)| {
+            if p[0].cmp(&p[1]) != Ordering::Greater {
+                Some(i + 1)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
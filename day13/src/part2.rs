@@ -0,0 +1,34 @@
+use crate::element::Element;
+
+fn decoder_key(mut pairs: Vec<Vec<Element>>) -> usize {
+    let p1 = vec![Element::Array(vec![Element::Digit(2)])];
+    let p2 = vec![Element::Array(vec![Element::Digit(6)])];
+
+    pairs.push(vec![Element::Array(vec![Element::Digit(2)])]);
+    pairs.push(vec![Element::Array(vec![Element::Digit(6)])]);
+    pairs.sort();
+
+    let mut p1_pos: Option<usize> = None;
+    let mut p2_pos: Option<usize> = None;
+    for (i, packet) in pairs.into_iter().enumerate() {
+        if packet == p1 {
+            p1_pos = Some(i + 1)
+        } else if packet == p2 {
+            p2_pos = Some(i + 1)
+        }
+
+        if p1_pos.is_some() && p2_pos.is_some() {
+            break;
+        }
+    }
+
+    let (Some(p1_pos), Some(p2_pos)) = (p1_pos, p2_pos) else {
+        panic!("Didn't find indices for packets");
+    };
+
+    p1_pos * p2_pos
+}
+
+pub fn run(packets: &[Vec<Element>]) -> usize {
+    decoder_key(packets.to_vec())
+}
@@ -0,0 +1,121 @@
+use std::fmt;
+
+use aoc_common::{parse_field, Compass, Grid, ParseError, Point, Solution};
+
+pub mod part1;
+pub mod part2;
+
+const FALL_ORDER: [Compass; 3] = [Compass::South, Compass::SouthWest, Compass::SouthEast];
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Material {
+    Sand,
+    Air,
+    Rock,
+}
+
+impl Material {
+    fn glyph(&self) -> char {
+        match self {
+            Material::Air => '.',
+            Material::Rock => '#',
+            Material::Sand => 'o',
+        }
+    }
+}
+
+impl fmt::Display for Material {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.glyph())
+    }
+}
+
+fn parse_line(input: &str, line: &str) -> Result<Vec<Point>, ParseError> {
+    line.split(" -> ")
+        .map(|l| {
+            let (x, y) = l
+                .split_once(',')
+                .ok_or_else(|| ParseError::new(Day14::DAY, input, l, "expected x,y"))?;
+            Ok(Point::new(
+                parse_field::<u32>(Day14::DAY, input, x)?.into(),
+                parse_field::<u32>(Day14::DAY, input, y)?.into(),
+            ))
+        })
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<Point>>, ParseError> {
+    aoc_common::lines(input)
+        .map(|line| parse_line(input, line))
+        .collect()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Vec<Vec<Point>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> usize {
+        part1::run(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> usize {
+        part2::run(input)
+    }
+}
+
+fn get_boundaries(input: &[Vec<Point>]) -> (usize, usize) {
+    let mut largest_x = 0;
+    let mut largest_y = 0;
+
+    for p in input.iter().flatten() {
+        largest_x = largest_x.max(p.x);
+        largest_y = largest_y.max(p.y);
+    }
+
+    (largest_x as usize, largest_y as usize)
+}
+
+fn draw_rock(map: &mut Grid<Material>, from: Point, to: Point) {
+    let step = (to - from).signum();
+    let mut position = from;
+    map[position] = Material::Rock;
+    while position != to {
+        position += step;
+        map[position] = Material::Rock;
+    }
+}
+
+fn simulate_move(current_position: Point, map: &Grid<Material>) -> Option<Point> {
+    FALL_ORDER
+        .into_iter()
+        .map(|direction| current_position + direction.offset())
+        .find(|&candidate| map.at(candidate) == Some(&Material::Air))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Point;
+
+    use crate::parse_line;
+
+    #[test]
+    fn test_parse_sample_line() {
+        let input = "498,4 -> 498,6 -> 496,6";
+        let res = parse_line(input, input).unwrap();
+        assert_eq!(
+            res,
+            vec![Point::new(498, 4), Point::new(498, 6), Point::new(496, 6)]
+        )
+    }
+}
+
+aoc_common::sample_tests!(crate::Day14);
@@ -0,0 +1,53 @@
+use aoc_common::{Grid, Point};
+
+use crate::{draw_rock, get_boundaries, simulate_move, Material};
+
+fn positions_to_map(input: &[Vec<Point>]) -> Grid<Material> {
+    let boundaries = get_boundaries(input);
+
+    let mut map = Grid::new(boundaries.0 + 1, boundaries.1 + 2, Material::Air);
+
+    for line in input.iter() {
+        for pair in line.windows(2) {
+            draw_rock(&mut map, pair[0], pair[1]);
+        }
+    }
+
+    map
+}
+
+fn count_resting_sand(mut map: Grid<Material>) -> usize {
+    let mut sand_count = 0;
+    loop {
+        sand_count += 1;
+        let mut current_position = Point::new(500, 0);
+
+        while let Some(new_position) = simulate_move(current_position, &map) {
+            if new_position.y >= (map.height() - 1) as i64 {
+                return sand_count - 1;
+            }
+            current_position = new_position;
+        }
+
+        map[current_position] = Material::Sand;
+    }
+}
+
+pub fn run(paths: &[Vec<Point>]) -> usize {
+    let map = positions_to_map(paths);
+    count_resting_sand(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::positions_to_map;
+    use crate::{parse_input, Material};
+
+    #[test]
+    fn test_convert_to_map() {
+        let input = "498,4 -> 498,6 -> 496,6";
+        let res = parse_input(input).unwrap();
+        let map = positions_to_map(&res);
+        assert_eq!(map[(498, 4)], Material::Rock);
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt;
+
+use aoc_common::{Interval, IntervalSet, ParseError, Point, Solution};
+
+mod parser;
+pub mod part1;
+pub mod part2;
+pub mod render;
+
+/// The x coordinates on `row` within reach of `sensor`, if the row is in reach at all.
+fn sensor_coverage_on_row(sensor: Point, beacon: Point, row: i64) -> Option<Interval<i64>> {
+    let reach = sensor.manhattan(beacon);
+    let reach_on_row = reach.checked_sub(sensor.y.abs_diff(row))? as i64;
+    Some(Interval::new(
+        sensor.x - reach_on_row,
+        sensor.x + reach_on_row,
+    ))
+}
+
+/// The x coordinates on `row` that are within reach of any sensor, including known beacons.
+pub fn row_coverage(sensors: &[(Point, Point)], row: i64) -> IntervalSet<i64> {
+    sensors
+        .iter()
+        .filter_map(|&(sensor, beacon)| sensor_coverage_on_row(sensor, beacon, row))
+        .collect()
+}
+
+/// The rectangle the distress beacon is known to be in, edges included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SearchArea {
+    pub x: Interval<i64>,
+    pub y: Interval<i64>,
+}
+
+impl SearchArea {
+    /// The square from `(0, 0)` to `(max, max)` used by the puzzle.
+    pub fn square(max: i64) -> Self {
+        SearchArea {
+            x: Interval::new(0, max),
+            y: Interval::new(0, max),
+        }
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.x.contains(point.x) && self.y.contains(point.y)
+    }
+}
+
+/// Every position in the search area is within reach of a sensor.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NoGap;
+
+impl fmt::Display for NoGap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no gap in the search area")
+    }
+}
+
+pub struct Day15 {
+    pub row: i64,
+    pub search_area: SearchArea,
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Day15 {
+            row: 2_000_000,
+            search_area: SearchArea::square(4_000_000),
+        }
+    }
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Vec<(Point, Point)>;
+    type Output1 = usize;
+    type Output2 = Result<i128, NoGap>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parser::parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> usize {
+        part1::run(input, self.row)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<i128, NoGap> {
+        part2::run(input, self.search_area)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Interval, Point, Solution};
+
+    use crate::{row_coverage, sensor_coverage_on_row, Day15};
+
+    #[test]
+    fn test_sensor_coverage_on_row() {
+        let sensor = Point::new(8, 7);
+        let beacon = Point::new(2, 10);
+        assert_eq!(
+            Some(Interval::new(2, 14)),
+            sensor_coverage_on_row(sensor, beacon, 10)
+        );
+        assert_eq!(
+            Some(Interval::new(8, 8)),
+            sensor_coverage_on_row(sensor, beacon, -2)
+        );
+        assert_eq!(None, sensor_coverage_on_row(sensor, beacon, 17));
+    }
+
+    #[test]
+    fn test_row_coverage() {
+        let sensors = Day15::default()
+            .parse(include_str!("../samples/sample.txt"))
+            .unwrap();
+        assert_eq!(
+            vec![Interval::new(-2, 24)],
+            row_coverage(&sensors, 10).iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Interval::new(-3, 13), Interval::new(15, 25)],
+            row_coverage(&sensors, 11).iter().collect::<Vec<_>>()
+        );
+    }
+}
+
+aoc_common::sample_tests!(crate::Day15 {
+    row: 10,
+    search_area: crate::SearchArea::square(20)
+});
@@ -0,0 +1,124 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1, space0, space1},
+    combinator::{all_consuming, opt, recognize},
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+    Finish, IResult,
+};
+
+use aoc_common::{parse_field, ParseError, Point, Solution};
+
+use crate::Day15;
+
+/// The unparsed `x` and `y` of a position.
+type Coordinates<'a> = (&'a str, &'a str);
+
+fn number(input: &str) -> IResult<&str, &str> {
+    recognize(pair(opt(char('-')), digit1))(input)
+}
+
+/// `x=2, y=18`
+fn coordinates(input: &str) -> IResult<&str, Coordinates<'_>> {
+    separated_pair(
+        preceded(pair(tag("x"), delimited(space0, char('='), space0)), number),
+        delimited(space0, char(','), space0),
+        preceded(pair(tag("y"), delimited(space0, char('='), space0)), number),
+    )(input)
+}
+
+/// `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`
+fn sensor(input: &str) -> IResult<&str, (Coordinates<'_>, Coordinates<'_>)> {
+    let words = |words: &'static [&'static str]| {
+        move |mut input| {
+            for word in words {
+                input = terminated(tag(*word), space1)(input)?.0;
+            }
+            Ok((input, ()))
+        }
+    };
+    separated_pair(
+        preceded(words(&["Sensor", "at"]), coordinates),
+        tuple((space0, char(':'), space0)),
+        preceded(words(&["closest", "beacon", "is", "at"]), coordinates),
+    )(input)
+}
+
+fn parse_point(input: &str, (x, y): Coordinates<'_>) -> Result<Point, ParseError> {
+    Ok(Point::new(
+        parse_field(Day15::DAY, input, x)?,
+        parse_field(Day15::DAY, input, y)?,
+    ))
+}
+
+pub fn parse_line(input: &str, line: &str) -> Result<(Point, Point), ParseError> {
+    let (_, (sensor, beacon)) = all_consuming(sensor)(line).finish().map_err(|e| {
+        let span = e
+            .input
+            .chars()
+            .next()
+            .map_or(e.input, |c| &e.input[..c.len_utf8()]);
+        ParseError::new(
+            Day15::DAY,
+            input,
+            span,
+            "expected \"Sensor at x=.., y=..: closest beacon is at x=.., y=..\"",
+        )
+    })?;
+    Ok((parse_point(input, sensor)?, parse_point(input, beacon)?))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(Point, Point)>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_line(input, line))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Point, Solution};
+
+    use super::parse_line;
+    use crate::Day15;
+
+    #[test]
+    fn test_parse_sample() {
+        let input = include_str!("../samples/sample.txt");
+        let top = input.lines().next().unwrap();
+        let parsed = parse_line(top, top).unwrap();
+
+        assert_eq!(parsed.0, Point::new(2, 18));
+        assert_eq!(parsed.1, Point::new(-2, 15));
+    }
+
+    #[test]
+    fn test_parse_loose_whitespace() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\r\n\r\n  Sensor  at x = 9,y=16 :closest beacon is  at x=10, y=16 \r\n";
+        let parsed = Day15::default().parse(input).unwrap();
+        assert_eq!(
+            vec![
+                (Point::new(2, 18), Point::new(-2, 15)),
+                (Point::new(9, 16), Point::new(10, 16))
+            ],
+            parsed
+        );
+    }
+
+    #[test]
+    fn test_parse_malformed_line() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon at x=10, y=16";
+        let error = Day15::default().parse(input).unwrap_err();
+        assert_eq!((2, 37), (error.line(), error.column()));
+        assert!(error
+            .to_string()
+            .starts_with("day 15, line 2, column 37: expected"));
+
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=99999999999999999999";
+        let error = Day15::default().parse(input).unwrap_err();
+        assert_eq!((1, 51), (error.line(), error.column()));
+        assert_eq!("99999999999999999999", error.text());
+    }
+}
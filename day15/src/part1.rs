@@ -0,0 +1,16 @@
+use std::collections::HashSet;
+
+use aoc_common::Point;
+
+use crate::row_coverage;
+
+pub fn run(items: &[(Point, Point)], row: i64) -> usize {
+    let coverage = row_coverage(items, row);
+    let beacons_on_row: HashSet<i64> = items
+        .iter()
+        .filter(|(_, beacon)| beacon.y == row && coverage.contains(beacon.x))
+        .map(|(_, beacon)| beacon.x)
+        .collect();
+
+    coverage.total_length() as usize - beacons_on_row.len()
+}
@@ -0,0 +1,134 @@
+use aoc_common::{Interval, IntervalSet, Point};
+
+use crate::{row_coverage, NoGap, SearchArea};
+
+fn covered(sensors: &[(Point, Point)], p: Point) -> bool {
+    sensors
+        .iter()
+        .any(|&(sensor, beacon)| sensor.manhattan(p) <= sensor.manhattan(beacon))
+}
+
+/// Where the diagonal lines `y = x + a` and `y = -x + b` cross, if that is on a whole position.
+fn intersection(a: i64, b: i64) -> Option<Point> {
+    ((b - a) % 2 == 0).then(|| Point::new((b - a) / 2, (a + b) / 2))
+}
+
+/// A lone uncovered position is surrounded by coverage, so unless it lies on the edge of the
+/// search area it is just outside the edges of at least two sensors' diamonds. Those edges lie
+/// on diagonal lines, and only their crossings have to be checked.
+pub fn find_gap_by_boundaries(sensors: &[(Point, Point)], area: SearchArea) -> Option<Point> {
+    let mut ascending = Vec::new();
+    let mut descending = Vec::new();
+    for &(sensor, beacon) in sensors {
+        let outside = sensor.manhattan(beacon) as i64 + 1;
+        ascending.extend([sensor.y - sensor.x - outside, sensor.y - sensor.x + outside]);
+        descending.extend([sensor.y + sensor.x - outside, sensor.y + sensor.x + outside]);
+    }
+
+    let corners = [
+        Point::new(area.x.start(), area.y.start()),
+        Point::new(area.x.end(), area.y.start()),
+        Point::new(area.x.start(), area.y.end()),
+        Point::new(area.x.end(), area.y.end()),
+    ];
+    ascending
+        .iter()
+        .flat_map(|&a| descending.iter().filter_map(move |&b| intersection(a, b)))
+        .chain(corners)
+        .find(|&p| area.contains(p) && !covered(sensors, p))
+}
+
+/// The first x in `within` that is not in `coverage`.
+fn first_uncovered(coverage: &IntervalSet<i64>, within: Interval<i64>) -> Option<i64> {
+    let mut x = within.start();
+    for covered in coverage.iter() {
+        if covered.start() > x {
+            break;
+        }
+        x = x.max(covered.end() + 1);
+    }
+    within.contains(x).then_some(x)
+}
+
+/// Scans the search area row by row for the first position no sensor reaches. Slower than
+/// following the boundaries, but finds a gap wherever it is.
+pub fn find_gap_by_rows(sensors: &[(Point, Point)], area: SearchArea) -> Option<Point> {
+    (area.y.start()..=area.y.end())
+        .find_map(|y| first_uncovered(&row_coverage(sensors, y), area.x).map(|x| Point::new(x, y)))
+}
+
+/// The tuning frequency of the gap, signed since the search area may reach below 0. Only when
+/// following the boundaries finds nothing is every row scanned, as a gap on the edge of the search
+/// area can be missed.
+pub fn run(sensors: &[(Point, Point)], area: SearchArea) -> Result<i128, NoGap> {
+    let gap = find_gap_by_boundaries(sensors, area)
+        .or_else(|| find_gap_by_rows(sensors, area))
+        .ok_or(NoGap)?;
+
+    Ok(i128::from(gap.x) * 4_000_000 + i128::from(gap.y))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Interval, IntervalSet, Point, Solution};
+
+    use super::{find_gap_by_boundaries, find_gap_by_rows, first_uncovered, intersection, run};
+    use crate::{Day15, NoGap, SearchArea};
+
+    #[test]
+    fn test_intersection() {
+        assert_eq!(Some(Point::new(2, 3)), intersection(1, 5));
+        assert_eq!(None, intersection(1, 4));
+    }
+
+    #[test]
+    fn test_first_uncovered() {
+        let coverage: IntervalSet<i64> = [Interval::new(-3, 13), Interval::new(15, 25)]
+            .into_iter()
+            .collect();
+        assert_eq!(Some(14), first_uncovered(&coverage, Interval::new(0, 20)));
+        assert_eq!(None, first_uncovered(&coverage, Interval::new(15, 20)));
+        assert_eq!(Some(26), first_uncovered(&coverage, Interval::new(15, 30)));
+    }
+
+    #[test]
+    fn test_search_strategies_agree() {
+        let sensors = Day15::default()
+            .parse(include_str!("../samples/sample.txt"))
+            .unwrap();
+        let gap = Some(Point::new(14, 11));
+        assert_eq!(
+            gap,
+            find_gap_by_boundaries(&sensors, SearchArea::square(20))
+        );
+        assert_eq!(gap, find_gap_by_rows(&sensors, SearchArea::square(20)));
+
+        let elsewhere = SearchArea {
+            x: Interval::new(-10, -4),
+            y: Interval::new(5, 6),
+        };
+        assert_eq!(
+            Some(Point::new(-10, 5)),
+            find_gap_by_rows(&sensors, elsewhere)
+        );
+    }
+
+    #[test]
+    fn test_run_below_zero() {
+        let sensors = Day15::default()
+            .parse(include_str!("../samples/sample.txt"))
+            .unwrap();
+        let area = SearchArea {
+            x: Interval::new(-10, -4),
+            y: Interval::new(5, 6),
+        };
+        assert_eq!(Ok(-10 * 4_000_000 + 5), run(&sensors, area));
+        assert_eq!(Ok(56_000_011), run(&sensors, SearchArea::square(20)));
+    }
+
+    #[test]
+    fn test_run_without_gap() {
+        let sensors = [(Point::new(0, 0), Point::new(90, 0))];
+        assert_eq!(Err(NoGap), run(&sensors, SearchArea::square(20)));
+    }
+}
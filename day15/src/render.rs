@@ -0,0 +1,113 @@
+use aoc_common::{image::Rgb, Grid, Point};
+
+use crate::SearchArea;
+
+/// What a cell of the map shows, in order of increasing precedence when a cell covers several
+/// positions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Cell {
+    Uncovered,
+    Covered,
+    Beacon,
+    Sensor,
+    Gap,
+}
+
+impl Cell {
+    pub fn glyph(self) -> char {
+        match self {
+            Cell::Uncovered => '.',
+            Cell::Covered => '#',
+            Cell::Beacon => 'B',
+            Cell::Sensor => 'S',
+            Cell::Gap => '*',
+        }
+    }
+
+    pub fn colour(self) -> Rgb {
+        match self {
+            Cell::Uncovered => Rgb(24, 24, 32),
+            Cell::Covered => Rgb(70, 110, 160),
+            Cell::Beacon => Rgb(250, 200, 40),
+            Cell::Sensor => Rgb(230, 60, 50),
+            Cell::Gap => Rgb(255, 255, 255),
+        }
+    }
+}
+
+/// Draws `area` with one cell per `scale` by `scale` positions. A cell counts as covered when
+/// its centre is, and shows any sensor, beacon or `gap` inside it.
+pub fn coverage_map(
+    sensors: &[(Point, Point)],
+    area: SearchArea,
+    scale: u32,
+    gap: Option<Point>,
+) -> Grid<Cell> {
+    assert!(scale > 0, "the scale has to be at least 1");
+    let scale = i64::from(scale);
+    let origin = Point::new(area.x.start(), area.y.start());
+    let cells = |length: u128| length.div_ceil(scale as u128);
+
+    let mut map = Grid::from_fn(
+        cells(area.x.length()) as usize,
+        cells(area.y.length()) as usize,
+        |x, y| {
+            let centre = origin + Point::from((x, y)) * scale + Point::new(scale / 2, scale / 2);
+            let covered = sensors
+                .iter()
+                .any(|&(sensor, beacon)| sensor.manhattan(centre) <= sensor.manhattan(beacon));
+            if covered {
+                Cell::Covered
+            } else {
+                Cell::Uncovered
+            }
+        },
+    );
+
+    let marks = sensors
+        .iter()
+        .flat_map(|&(sensor, beacon)| [(sensor, Cell::Sensor), (beacon, Cell::Beacon)])
+        .chain(gap.map(|gap| (gap, Cell::Gap)));
+    for (position, mark) in marks {
+        if !area.contains(position) {
+            continue;
+        }
+        let offset = position - origin;
+        let cell = &mut map[Point::new(offset.x / scale, offset.y / scale)];
+        *cell = mark.max(*cell);
+    }
+
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Interval, Point, Solution};
+
+    use super::{coverage_map, Cell};
+    use crate::{Day15, SearchArea};
+
+    #[test]
+    fn test_coverage_map() {
+        let sensors = Day15::default()
+            .parse(include_str!("../samples/sample.txt"))
+            .unwrap();
+        let map = coverage_map(
+            &sensors,
+            SearchArea::square(20),
+            1,
+            Some(Point::new(14, 11)),
+        );
+        let drawing = map.render(|_, cell| cell.glyph());
+        assert_eq!(Some("S#############*######"), drawing.lines().nth(11));
+        assert_eq!(Cell::Beacon, map[(2, 10)]);
+
+        let area = SearchArea {
+            x: Interval::new(-4, 25),
+            y: Interval::new(0, 21),
+        };
+        let map = coverage_map(&sensors, area, 10, None);
+        assert_eq!((3, 3), (map.width(), map.height()));
+        assert_eq!(Cell::Sensor, map[(1, 1)]);
+    }
+}
@@ -1,5 +1,10 @@
-#[derive(PartialEq, Eq, Debug)]
-enum Shape {
+use aoc_common::{ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Shape {
     Rock,
     Paper,
     Scissor,
@@ -23,20 +28,6 @@ impl TryInto<Shape> for &str {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Round> {
-    input
-        .lines()
-        .map(str::trim)
-        .map(|r| {
-            let choices: Vec<&str> = r.split_whitespace().collect();
-            let opponent: Shape = choices[0].try_into().expect("Failed to convert to shape");
-            let me: Shape = choices[1].try_into().expect("Failed to convert to shape");
-
-            Round { me, opponent }
-        })
-        .collect()
-}
-
 fn run_round(round: &Round) -> u64 {
     match round.me {
         Shape::Paper => {
@@ -66,24 +57,58 @@ fn run_round(round: &Round) -> u64 {
     }
 }
 
-fn main() {
-    let input = include_str!("../../input.txt");
-    let points: u64 = parse_input(input).into_iter().map(|r| run_round(&r)).sum();
-    println!("{points}")
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(Shape, String)>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        aoc_common::lines(input)
+            .map(|r| {
+                let (opponent, response) = r
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::new(Self::DAY, input, r, "expected two columns"))?;
+                let response = response.trim();
+                let opponent: Shape = opponent
+                    .try_into()
+                    .map_err(|e| ParseError::new(Self::DAY, input, opponent, e))?;
+                if !["X", "Y", "Z"].contains(&response) {
+                    return Err(ParseError::new(
+                        Self::DAY,
+                        input,
+                        response,
+                        "expected X, Y or Z",
+                    ));
+                }
+                Ok((opponent, response.to_owned()))
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> u64 {
+        part1::run(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> u64 {
+        part2::run(input)
+    }
 }
 
 #[cfg(test)]
-mod test {
-    use crate::{parse_input, run_round, Round, Shape};
+mod tests {
+    use aoc_common::Solution;
 
-    #[test]
-    fn test_parse_input() {
-        let input = "A Y
-        B X
-        C Z";
+    use crate::{run_round, Day2, Round, Shape};
 
-        let parsed = parse_input(input);
-        assert_eq!(parsed[0].me, Shape::Paper);
+    #[test]
+    fn test_parse_unknown_shape() {
+        let error = Day2.parse("A Y\nB W\n").unwrap_err();
+        assert_eq!((2, 3), (error.line(), error.column()));
+        assert_eq!("W", error.text());
     }
 
     #[test]
@@ -119,3 +144,5 @@ mod test {
         assert_eq!(6, res)
     }
 }
+
+aoc_common::sample_tests!(crate::Day2);
@@ -0,0 +1,40 @@
+use crate::{run_round, Round, Shape};
+
+fn to_rounds(guide: &[(Shape, String)]) -> Vec<Round> {
+    guide
+        .iter()
+        .map(|(opponent, response)| {
+            let me: Shape = response
+                .as_str()
+                .try_into()
+                .expect("Failed to convert to shape");
+
+            Round {
+                me,
+                opponent: opponent.clone(),
+            }
+        })
+        .collect()
+}
+
+pub fn run(guide: &[(Shape, String)]) -> u64 {
+    to_rounds(guide).iter().map(run_round).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::to_rounds;
+    use crate::{Day2, Shape};
+
+    #[test]
+    fn test_parse_input() {
+        let input = "A Y
+        B X
+        C Z";
+
+        let parsed = to_rounds(&Day2.parse(input).unwrap());
+        assert_eq!(parsed[0].me, Shape::Paper);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{run_round, Round, Shape};
+
+fn to_rounds(guide: &[(Shape, String)]) -> Vec<Round> {
+    guide
+        .iter()
+        .map(|(opponent, outcome)| {
+            let me: Shape = match (opponent, outcome.as_str()) {
+                (Shape::Rock, "X") => Shape::Scissor,
+                (Shape::Rock, "Y") => Shape::Rock,
+                (Shape::Rock, "Z") => Shape::Paper,
+                (Shape::Paper, "X") => Shape::Rock,
+                (Shape::Paper, "Y") => Shape::Paper,
+                (Shape::Paper, "Z") => Shape::Scissor,
+                (Shape::Scissor, "X") => Shape::Paper,
+                (Shape::Scissor, "Y") => Shape::Scissor,
+                (Shape::Scissor, "Z") => Shape::Rock,
+                _ => panic!("Unexpected combination"),
+            };
+
+            Round {
+                me,
+                opponent: opponent.clone(),
+            }
+        })
+        .collect()
+}
+
+pub fn run(guide: &[(Shape, String)]) -> u64 {
+    to_rounds(guide).iter().map(run_round).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::to_rounds;
+    use crate::{Day2, Shape};
+
+    #[test]
+    fn test_parse_input() {
+        let input = "A Y
+        B X
+        C Z";
+
+        let parsed = to_rounds(&Day2.parse(input).unwrap());
+        assert_eq!(parsed[0].me, Shape::Rock);
+        assert_eq!(parsed[0].opponent, Shape::Rock);
+
+        assert_eq!(parsed[1].me, Shape::Rock);
+        assert_eq!(parsed[1].opponent, Shape::Paper);
+
+        assert_eq!(parsed[2].me, Shape::Rock);
+        assert_eq!(parsed[2].opponent, Shape::Scissor);
+    }
+}
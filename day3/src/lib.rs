@@ -0,0 +1,62 @@
+use aoc_common::{ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+fn get_value(item: char) -> u32 {
+    let converted = item.to_ascii_uppercase() as u32 - 64;
+
+    if item.is_ascii_uppercase() {
+        converted + 26
+    } else {
+        converted
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        aoc_common::lines(input)
+            .map(
+                |line| match line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                    Some((i, c)) => Err(ParseError::new(
+                        Self::DAY,
+                        input,
+                        &line[i..i + c.len_utf8()],
+                        "expected an item letter",
+                    )),
+                    None => Ok(line.to_owned()),
+                },
+            )
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> u32 {
+        part1::run(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> u32 {
+        part2::run(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::get_value;
+    use test_case::test_case;
+
+    #[test_case('p', 16)]
+    #[test_case('L', 38)]
+    fn test_convert(item: char, value: u32) {
+        assert_eq!(value, get_value(item))
+    }
+}
+
+aoc_common::sample_tests!(crate::Day3);
@@ -1,3 +1,5 @@
+use crate::get_value;
+
 fn find_duplicate(rucksack: &str) -> char {
     let size = rucksack.len();
     let left = rucksack.get(..=(size / 2)).unwrap();
@@ -12,32 +14,17 @@ fn find_duplicate(rucksack: &str) -> char {
     panic!("No duplicate found");
 }
 
-fn get_value(item: char) -> u32 {
-    let converted = item.to_ascii_uppercase() as u32 - 64;
-
-    if item.is_ascii_uppercase() {
-        converted + 26
-    } else {
-        converted
-    }
-}
-
-fn main() {
-    let input = include_str!("../../input.txt");
-    let sum: u32 = input
-        .trim()
-        .lines()
-        .map(str::trim)
-        .map(find_duplicate)
+pub fn run(rucksacks: &[String]) -> u32 {
+    rucksacks
+        .iter()
+        .map(|r| find_duplicate(r))
         .map(get_value)
-        .sum();
-
-    println!("{sum}")
+        .sum()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{find_duplicate, get_value};
+    use super::find_duplicate;
     use test_case::test_case;
 
     #[test_case("vJrwpWtwJgWrhcsFMMfFFhFp", 'p')]
@@ -49,10 +36,4 @@ mod tests {
     fn test_samples(sample: &str, expected: char) {
         assert_eq!(expected, find_duplicate(sample));
     }
-
-    #[test_case('p', 16)]
-    #[test_case('L', 38)]
-    fn test_convert(item: char, value: u32) {
-        assert_eq!(value, get_value(item))
-    }
 }
@@ -0,0 +1,24 @@
+use std::collections::HashSet;
+
+use crate::get_value;
+
+fn find_badge(rucksacks: &mut [HashSet<char>]) -> char {
+    let (intersection, others) = rucksacks.split_at_mut(1);
+    let intersection = &mut intersection[0];
+    for other in others {
+        intersection.retain(|e| other.contains(e))
+    }
+
+    *intersection.iter().next().unwrap()
+}
+
+pub fn run(rucksacks: &[String]) -> u32 {
+    rucksacks
+        .iter()
+        .map(|l| l.chars().collect::<HashSet<_>>())
+        .collect::<Vec<_>>()
+        .chunks_exact_mut(3)
+        .map(find_badge)
+        .map(get_value)
+        .sum()
+}
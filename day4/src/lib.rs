@@ -0,0 +1,153 @@
+use std::fmt;
+
+use aoc_common::{parse_field, Interval, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub type Pair = (Interval<u64>, Interval<u64>);
+
+/// The sections two elves of a pair both have to clean.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Overlap {
+    /// Index of the pair in the puzzle input
+    pub pair: usize,
+    pub left: Interval<u64>,
+    pub right: Interval<u64>,
+    pub shared: Interval<u64>,
+}
+
+impl Overlap {
+    /// Whether one elf's sections all lie within the other's.
+    pub fn is_contained(&self) -> bool {
+        self.shared == self.left || self.shared == self.right
+    }
+}
+
+impl fmt::Display for Overlap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "pair {}: {},{} share {}",
+            self.pair + 1,
+            self.left,
+            self.right,
+            self.shared
+        )?;
+        if self.is_contained() {
+            write!(f, " (fully contained)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Every pair whose assignments overlap, in input order.
+pub fn find_overlaps(pairs: &[Pair]) -> impl Iterator<Item = Overlap> + '_ {
+    pairs
+        .iter()
+        .enumerate()
+        .filter_map(|(pair, &(left, right))| {
+            left.intersection(right).map(|shared| Overlap {
+                pair,
+                left,
+                right,
+                shared,
+            })
+        })
+}
+
+fn parse_assignment(input: &str, range: &str) -> Result<Interval<u64>, ParseError> {
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(Day4::DAY, input, range, "expected a range like 2-4"))?;
+    let start: u64 = parse_field(Day4::DAY, input, start)?;
+    let end: u64 = parse_field(Day4::DAY, input, end)?;
+    if start > end {
+        return Err(ParseError::new(
+            Day4::DAY,
+            input,
+            range,
+            "the range ends before it starts",
+        ));
+    }
+
+    Ok(Interval::new(start, end))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Pair>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        aoc_common::lines(input)
+            .map(|p| {
+                let (left, right) = p
+                    .split_once(',')
+                    .ok_or_else(|| ParseError::new(Self::DAY, input, p, "expected two ranges"))?;
+                Ok((
+                    parse_assignment(input, left)?,
+                    parse_assignment(input, right)?,
+                ))
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> usize {
+        part1::run(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> usize {
+        part2::run(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Interval, Solution};
+
+    use crate::{find_overlaps, parse_assignment, Day4};
+
+    #[test]
+    fn test_parse_interval() {
+        let interval = "1-3";
+        let res = parse_assignment(interval, interval).unwrap();
+        assert_eq!(Interval::new(1, 3), res);
+
+        let interval = "0-18446744073709551614";
+        let res = parse_assignment(interval, interval).unwrap();
+        assert_eq!(u128::from(u64::MAX), res.length());
+    }
+
+    #[test]
+    fn test_parse_malformed_range() {
+        let error = Day4.parse("2-4,6-8\n2-3,4_5\n").unwrap_err();
+        assert_eq!((2, 5), (error.line(), error.column()));
+        assert_eq!("4_5", error.text());
+
+        let error = Day4.parse("2-4,6-x\n").unwrap_err();
+        assert_eq!((1, 7), (error.line(), error.column()));
+    }
+
+    #[test]
+    fn test_list_overlaps() {
+        let pairs = Day4
+            .parse("2-4,6-8\n5-7,7-9\n2-8,3-7\n100000000000-100000000005,100000000003-200000000000")
+            .unwrap();
+        let overlaps: Vec<String> = find_overlaps(&pairs).map(|o| o.to_string()).collect();
+        assert_eq!(
+            vec![
+                "pair 2: 5-7,7-9 share 7-7",
+                "pair 3: 2-8,3-7 share 3-7 (fully contained)",
+                "pair 4: 100000000000-100000000005,100000000003-200000000000 share 100000000003-100000000005",
+            ],
+            overlaps
+        );
+    }
+}
+
+aoc_common::sample_tests!(crate::Day4);
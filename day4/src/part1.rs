@@ -0,0 +1,25 @@
+use crate::{find_overlaps, Pair};
+
+pub fn run(pairs: &[Pair]) -> usize {
+    find_overlaps(pairs).filter(|o| o.is_contained()).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::run;
+    use crate::Day4;
+
+    #[test]
+    fn test_overlap() {
+        let input = Day4.parse("2-8,3-7").unwrap();
+        assert_eq!(1, run(&input));
+    }
+
+    #[test]
+    fn test_not_full_overlap() {
+        let input = Day4.parse("2-8,1-7").unwrap();
+        assert_eq!(0, run(&input));
+    }
+}
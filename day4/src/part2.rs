@@ -0,0 +1,31 @@
+use crate::{find_overlaps, Pair};
+
+pub fn run(pairs: &[Pair]) -> usize {
+    find_overlaps(pairs).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::run;
+    use crate::Day4;
+
+    #[test]
+    fn test_full_overlap() {
+        let input = Day4.parse("2-8,3-7").unwrap();
+        assert_eq!(1, run(&input));
+    }
+
+    #[test]
+    fn test_partial_overlap() {
+        let input = Day4.parse("1-3,2-4").unwrap();
+        assert_eq!(1, run(&input))
+    }
+
+    #[test]
+    fn test_no_overlap() {
+        let input = Day4.parse("1-3,4-8").unwrap();
+        assert_eq!(0, run(&input));
+    }
+}
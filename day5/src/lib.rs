@@ -0,0 +1,218 @@
+use aoc_common::{parse_field, ParseError, Solution};
+use itertools::Itertools;
+use std::{collections::VecDeque, fmt};
+
+pub mod part1;
+pub mod part2;
+
+type Move = (usize, usize, usize);
+
+fn parse_start(input: &str, columns: &str) -> Result<Vec<VecDeque<char>>, ParseError> {
+    let mut lines = columns.lines().rev();
+    let last_line = lines
+        .next()
+        .ok_or_else(|| ParseError::new(Day5::DAY, input, columns, "expected stack numbers"))?;
+    let nr_of_columns = (last_line.len() + 1) / 4;
+
+    let mut stacks: Vec<VecDeque<char>> = (0..nr_of_columns).map(|_| VecDeque::new()).collect();
+    for line in lines {
+        for (index, c) in line.chars().skip(1).step_by(4).enumerate() {
+            if c == ' ' {
+                continue;
+            }
+            stacks
+                .get_mut(index)
+                .ok_or_else(|| {
+                    ParseError::new(
+                        Day5::DAY,
+                        input,
+                        line,
+                        "crate outside of the numbered stacks",
+                    )
+                })?
+                .push_front(c);
+        }
+    }
+
+    Ok(stacks)
+}
+
+fn parse_moves(input: &str, moves: &str, stacks: usize) -> Result<Vec<Move>, ParseError> {
+    moves
+        .lines()
+        .map(|line| {
+            let fields: Vec<usize> = line
+                .split_whitespace()
+                .skip(1)
+                .step_by(2)
+                .map(|r| parse_field(Day5::DAY, input, r))
+                .collect::<Result<_, _>>()?;
+            let (count, from, to) = fields.into_iter().collect_tuple().ok_or_else(|| {
+                ParseError::new(Day5::DAY, input, line, "expected \"move N from A to B\"")
+            })?;
+            if !(1..=stacks).contains(&from) || !(1..=stacks).contains(&to) {
+                return Err(ParseError::new(
+                    Day5::DAY,
+                    input,
+                    line,
+                    format!("stacks are numbered 1 to {stacks}"),
+                ));
+            }
+            if from == to {
+                return Err(ParseError::new(
+                    Day5::DAY,
+                    input,
+                    line,
+                    "expected a move between two different stacks",
+                ));
+            }
+            Ok((count, from, to))
+        })
+        .collect()
+}
+
+/// A move took more crates than were on its stack at the time.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NotEnoughCrates {
+    /// The number of the move, counting from 1
+    pub step: usize,
+    pub stack: usize,
+    pub wanted: usize,
+    pub held: usize,
+}
+
+impl fmt::Display for NotEnoughCrates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} takes {} crates from stack {}, which only holds {}",
+            self.step, self.wanted, self.stack, self.held
+        )
+    }
+}
+
+/// Checks that the move at `index` has enough crates to take from its stack.
+fn check_move(
+    stacks: &[VecDeque<char>],
+    index: usize,
+    &(count, from, _): &Move,
+) -> Result<(), NotEnoughCrates> {
+    let held = stacks[from - 1].len();
+    if count > held {
+        return Err(NotEnoughCrates {
+            step: index + 1,
+            stack: from,
+            wanted: count,
+            held,
+        });
+    }
+    Ok(())
+}
+
+/// The crate on top of every stack, skipping the ones left empty.
+fn top_crates(stacks: Vec<VecDeque<char>>) -> String {
+    stacks
+        .into_iter()
+        .filter_map(|mut stack| stack.pop_front())
+        .collect()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = (Vec<VecDeque<char>>, Vec<Move>);
+    type Output1 = Result<String, NotEnoughCrates>;
+    type Output2 = Result<String, NotEnoughCrates>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let (start, moves) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::new(
+                Self::DAY,
+                input,
+                &input[input.len()..],
+                "expected a blank line between stacks and moves",
+            )
+        })?;
+        let stacks = parse_start(input, start)?;
+        let moves = parse_moves(input, moves.trim_end(), stacks.len())?;
+        Ok((stacks, moves))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<String, NotEnoughCrates> {
+        part1::run(&input.0, &input.1)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<String, NotEnoughCrates> {
+        part2::run(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use crate::{parse_start, Day5, NotEnoughCrates};
+
+    #[test]
+    fn test_parse() {
+        let input = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 ";
+        let mut res = parse_start(input, input).unwrap();
+        assert_eq!(res[1].pop_front().unwrap(), 'D');
+        assert_eq!(res[1].pop_front().unwrap(), 'C');
+        assert_eq!(res[1].pop_front().unwrap(), 'M');
+        assert_eq!(res[0].pop_front().unwrap(), 'N');
+        assert_eq!(res[0].pop_front().unwrap(), 'Z');
+        assert_eq!(res[2].pop_front().unwrap(), 'P');
+    }
+
+    #[test]
+    fn test_parse_invalid_move() {
+        let input = "[A] [B]\n 1   2 \n\nmove 1 from 1 to 2\nmove 1 from 2 to 3\n";
+        let error = Day5.parse(input).unwrap_err();
+        assert_eq!((5, 1), (error.line(), error.column()));
+    }
+
+    #[test]
+    fn test_parse_move_to_same_stack() {
+        let input = "[A] [B]\n 1   2 \n\nmove 1 from 1 to 2\nmove 1 from 2 to 2\n";
+        let error = Day5.parse(input).unwrap_err();
+        assert_eq!((5, 1), (error.line(), error.column()));
+        assert!(error
+            .to_string()
+            .contains("expected a move between two different stacks"));
+    }
+
+    #[test]
+    fn test_not_enough_crates() {
+        let input = Day5
+            .parse("[A]    \n[B] [C]\n 1   2 \n\nmove 1 from 2 to 1\nmove 4 from 1 to 2\n")
+            .unwrap();
+        let error = NotEnoughCrates {
+            step: 2,
+            stack: 1,
+            wanted: 4,
+            held: 3,
+        };
+        assert_eq!(
+            "move 2 takes 4 crates from stack 1, which only holds 3",
+            error.to_string()
+        );
+        assert_eq!(Err(error), Day5.part1(&input));
+        assert_eq!(
+            Err(NotEnoughCrates {
+                step: 2,
+                stack: 1,
+                wanted: 4,
+                held: 3
+            }),
+            Day5.part2(&input)
+        );
+    }
+}
+
+aoc_common::sample_tests!(crate::Day5);
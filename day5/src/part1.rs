@@ -0,0 +1,17 @@
+use std::collections::VecDeque;
+
+use crate::{check_move, top_crates, Move, NotEnoughCrates};
+
+pub fn run(stacks: &[VecDeque<char>], moves: &[Move]) -> Result<String, NotEnoughCrates> {
+    let mut stacks = stacks.to_vec();
+
+    for (index, m) in moves.iter().enumerate() {
+        check_move(&stacks, index, m)?;
+        for _ in 0..m.0 {
+            let c = stacks[m.1 - 1].pop_front().expect("the move was checked");
+            stacks[m.2 - 1].push_front(c);
+        }
+    }
+
+    Ok(top_crates(stacks))
+}
@@ -0,0 +1,22 @@
+use std::collections::VecDeque;
+
+use crate::{check_move, top_crates, Move, NotEnoughCrates};
+
+pub fn run(stacks: &[VecDeque<char>], moves: &[Move]) -> Result<String, NotEnoughCrates> {
+    let mut stacks = stacks.to_vec();
+
+    for (index, m) in moves.iter().enumerate() {
+        check_move(&stacks, index, m)?;
+        let split_index = (m.1 + m.2) / 2;
+        let (p1, p2) = stacks.split_at_mut(split_index);
+        let (from, to) = if m.1 < m.2 {
+            (&mut p1[m.1 - 1], &mut p2[m.2 - split_index - 1])
+        } else {
+            (&mut p2[m.1 - split_index - 1], &mut p1[m.2 - 1])
+        };
+
+        from.drain(..m.0).rev().for_each(|i| to.push_front(i));
+    }
+
+    Ok(top_crates(stacks))
+}
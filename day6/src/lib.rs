@@ -0,0 +1,63 @@
+use aoc_common::{ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let signal = input.trim();
+        match signal.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+            Some((i, c)) => Err(ParseError::new(
+                Self::DAY,
+                input,
+                &signal[i..i + c.len_utf8()],
+                "expected a lowercase letter",
+            )),
+            None => Ok(signal.to_owned()),
+        }
+    }
+
+    fn part1(&self, input: &Self::Input) -> usize {
+        part1::run(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> usize {
+        part2::run(input)
+    }
+}
+
+fn find_marker<const MESSAGE_LENGTH: usize>(input: &str) -> usize {
+    let (intro, rest) = input.split_at(MESSAGE_LENGTH - 1);
+    let mut start = ['0'; MESSAGE_LENGTH];
+    intro
+        .chars()
+        .enumerate()
+        .for_each(|(i, c)| start[i + 1] = c);
+    rest.chars()
+        .scan(start, |state, c| {
+            state.rotate_left(1);
+            state[state.len() - 1] = c;
+            Some(*state)
+        })
+        .enumerate()
+        .find_map(|(i, c)| {
+            for (index, ch) in c.iter().enumerate() {
+                let rest = &c[(index + 1)..];
+                if rest.contains(ch) {
+                    return None;
+                }
+            }
+            Some(i + MESSAGE_LENGTH)
+        })
+        .expect("Didn't find start of marker")
+}
+
+aoc_common::sample_tests!(crate::Day6);
@@ -0,0 +1,20 @@
+use crate::find_marker;
+
+pub fn run(input: &str) -> usize {
+    find_marker::<4>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::run;
+
+    #[test_case("bvwbjplbgvbhsrlpgdmjqwftvncz", 5)]
+    #[test_case("nppdvjthqldpwncqszvftbrmjlhg", 6)]
+    #[test_case("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10)]
+    #[test_case("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11)]
+    fn test_find_marker(input: &str, index: usize) {
+        assert_eq!(index, run(input));
+    }
+}
@@ -0,0 +1,21 @@
+use crate::find_marker;
+
+pub fn run(input: &str) -> usize {
+    find_marker::<14>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::run;
+
+    #[test_case("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 19)]
+    #[test_case("bvwbjplbgvbhsrlpgdmjqwftvncz", 23)]
+    #[test_case("nppdvjthqldpwncqszvftbrmjlhg", 23)]
+    #[test_case("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29)]
+    #[test_case("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26)]
+    fn test_find_marker(input: &str, index: usize) {
+        assert_eq!(index, run(input));
+    }
+}
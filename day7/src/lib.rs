@@ -0,0 +1,85 @@
+use aoc_common::{parse_field, ParseError, Solution};
+use itertools::Itertools;
+use std::{collections::HashMap, path::PathBuf};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = HashMap<PathBuf, u64>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        directory_sizes(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> u64 {
+        part1::run(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> u64 {
+        part2::run(input)
+    }
+}
+
+fn directory_sizes(input: &str) -> Result<HashMap<PathBuf, u64>, ParseError> {
+    let mut current_path = PathBuf::new();
+    let mut directories: HashMap<PathBuf, u64> = HashMap::new();
+    let commands = input.split('$').skip(1).map(str::trim);
+
+    for cmd in commands {
+        if cmd.starts_with("cd") {
+            let (_, path) = cmd
+                .split_whitespace()
+                .collect_tuple::<(&str, &str)>()
+                .ok_or_else(|| ParseError::new(Day7::DAY, input, cmd, "expected \"cd <dir>\""))?;
+
+            match path {
+                ".." => {
+                    current_path = current_path
+                        .parent()
+                        .ok_or_else(|| {
+                            ParseError::new(Day7::DAY, input, path, "already at the top")
+                        })?
+                        .to_path_buf()
+                }
+                p => current_path.push(p),
+            };
+        } else if cmd.starts_with("ls") {
+            let mut dir_size = 0;
+            for entry in cmd.lines().skip(1) {
+                match entry.split_once(' ') {
+                    Some(("dir", _)) => {}
+                    Some((size, _)) => dir_size += parse_field::<u64>(Day7::DAY, input, size)?,
+                    None => {
+                        return Err(ParseError::new(
+                            Day7::DAY,
+                            input,
+                            entry,
+                            "expected \"dir <name>\" or \"<size> <name>\"",
+                        ))
+                    }
+                }
+            }
+
+            directories.insert(current_path.clone(), dir_size);
+
+            let mut current_walk = current_path.parent();
+            while let Some(parent) = current_walk {
+                current_walk = parent.parent();
+                *directories.entry(parent.to_path_buf()).or_default() += dir_size;
+            }
+        } else {
+            return Err(ParseError::new(Day7::DAY, input, cmd, "unknown command"));
+        }
+    }
+
+    Ok(directories)
+}
+
+aoc_common::sample_tests!(crate::Day7);
@@ -0,0 +1,5 @@
+use std::{collections::HashMap, path::PathBuf};
+
+pub fn run(directories: &HashMap<PathBuf, u64>) -> u64 {
+    directories.values().filter(|d| **d <= 100_000).sum()
+}
@@ -0,0 +1,15 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+pub fn run(directories: &HashMap<PathBuf, u64>) -> u64 {
+    let current_free = 70_000_000 - directories.get(Path::new("/")).unwrap();
+    let needed_to_free = 30_000_000 - current_free;
+
+    *directories
+        .values()
+        .filter(|e| **e > needed_to_free)
+        .min()
+        .unwrap()
+}
@@ -0,0 +1,61 @@
+use aoc_common::{Grid, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Grid<i8>;
+    type Output1 = usize;
+    type Output2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> usize {
+        part1::run(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> u64 {
+        part2::run(input)
+    }
+}
+
+fn parse_grid(input: &str) -> Result<Grid<i8>, ParseError> {
+    Grid::parse(Day8::DAY, input, |h| {
+        h.to_digit(10)
+            .map(|h| h as i8)
+            .ok_or("expected a tree height")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_grid;
+
+    pub const SAMPLE_GRID: &str = include_str!("../samples/sample.txt");
+
+    pub const UNEVEN_SAMPLE: &str = "3037
+2551
+6533
+3354
+3539";
+
+    #[test]
+    fn test_parse_grid() {
+        let res = parse_grid(SAMPLE_GRID).unwrap();
+        assert_eq!(5, res[(1, 1)]);
+    }
+
+    #[test]
+    fn test_parse_uneven_grid() {
+        let res = parse_grid(UNEVEN_SAMPLE).unwrap();
+        assert_eq!(1, res[(3, 1)]);
+    }
+}
+
+aoc_common::sample_tests!(crate::Day8);
@@ -0,0 +1,151 @@
+use aoc_common::Grid;
+
+#[derive(Debug, Clone)]
+struct SupportFrom(i8, i8);
+
+#[derive(Debug, Eq, PartialEq)]
+struct SupportFromAll {
+    top: i8,
+    left: i8,
+    right: i8,
+    bottom: i8,
+}
+
+fn build_max_supported_grid(grid: &Grid<i8>) -> Grid<SupportFromAll> {
+    let grid_width = grid.width();
+    let grid_height = grid.height();
+
+    let mut max_supported_grid_top_left = Grid::new(grid_width, grid_height, SupportFrom(-1, -1));
+    for (x, y) in grid.positions() {
+        let support_from_x = if x == 0 {
+            -1
+        } else {
+            let tree_left = grid[(x - 1, y)];
+            let support_at_left = max_supported_grid_top_left[(x - 1, y)].1;
+            tree_left.max(support_at_left)
+        };
+        let support_from_y = if y == 0 {
+            -1
+        } else {
+            let tree_above = grid[(x, y - 1)];
+            let support_at_above = max_supported_grid_top_left[(x, y - 1)].0;
+            tree_above.max(support_at_above)
+        };
+        max_supported_grid_top_left[(x, y)] = SupportFrom(support_from_y, support_from_x);
+    }
+
+    let mut max_supported_grid_bottom_right =
+        Grid::new(grid_width, grid_height, SupportFrom(-1, -1));
+    for (x, y) in grid.positions().rev() {
+        let support_from_x = if x == grid_width - 1 {
+            -1
+        } else {
+            let right_tree = grid[(x + 1, y)];
+            let support_at_right = max_supported_grid_bottom_right[(x + 1, y)].1;
+            right_tree.max(support_at_right)
+        };
+        let support_from_y = if y == grid_height - 1 {
+            -1
+        } else {
+            let tree_below = grid[(x, y + 1)];
+            let support_at_below = max_supported_grid_bottom_right[(x, y + 1)].0;
+            tree_below.max(support_at_below)
+        };
+        max_supported_grid_bottom_right[(x, y)] = SupportFrom(support_from_y, support_from_x);
+    }
+
+    Grid::from_fn(grid_width, grid_height, |x, y| {
+        let top_left = &max_supported_grid_top_left[(x, y)];
+        let bottom_right = &max_supported_grid_bottom_right[(x, y)];
+        SupportFromAll {
+            left: top_left.1,
+            top: top_left.0,
+            bottom: bottom_right.0,
+            right: bottom_right.1,
+        }
+    })
+}
+
+fn calc_visible_trees(grid: &Grid<i8>) -> usize {
+    let support_grid = build_max_supported_grid(grid);
+
+    grid.iter()
+        .filter(|&(position, current_point)| {
+            let support_at_point = &support_grid[position];
+            [
+                support_at_point.bottom,
+                support_at_point.left,
+                support_at_point.top,
+                support_at_point.right,
+            ]
+            .iter()
+            .min()
+            .unwrap()
+                < current_point
+        })
+        .count()
+}
+
+pub fn run(grid: &Grid<i8>) -> usize {
+    calc_visible_trees(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_max_supported_grid, calc_visible_trees, SupportFromAll};
+    use crate::{
+        parse_grid,
+        tests::{SAMPLE_GRID, UNEVEN_SAMPLE},
+    };
+
+    #[test]
+    fn test_build_max_support_grid_uneven() {
+        let res = parse_grid(UNEVEN_SAMPLE).unwrap();
+        let supported_grid = build_max_supported_grid(&res);
+
+        let point = &supported_grid[(1, 1)];
+        assert_eq!(
+            SupportFromAll {
+                left: 2,
+                top: 0,
+                right: 5,
+                bottom: 5
+            },
+            *point
+        );
+    }
+
+    #[test]
+    fn test_build_max_supported_grid() {
+        let res = parse_grid(SAMPLE_GRID).unwrap();
+        let supported_grid = build_max_supported_grid(&res);
+
+        let point = &supported_grid[(1, 0)];
+        assert_eq!(
+            SupportFromAll {
+                left: 3,
+                top: -1,
+                right: 7,
+                bottom: 5
+            },
+            *point
+        );
+
+        let point = &supported_grid[(2, 2)];
+        assert_eq!(
+            SupportFromAll {
+                left: 6,
+                top: 5,
+                bottom: 5,
+                right: 3
+            },
+            *point
+        );
+    }
+
+    #[test]
+    fn test_calc_trees() {
+        let visible_trees = calc_visible_trees(&parse_grid(SAMPLE_GRID).unwrap());
+        assert_eq!(21, visible_trees);
+    }
+}
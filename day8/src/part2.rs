@@ -0,0 +1,57 @@
+use aoc_common::{Direction, Grid};
+
+fn count_trees<'a>(current_tree: &i8, tree_direction: impl IntoIterator<Item = &'a i8>) -> u64 {
+    let mut seen_trees = 0;
+    for tree in tree_direction.into_iter() {
+        seen_trees += 1;
+        if tree >= current_tree {
+            break;
+        }
+    }
+
+    seen_trees
+}
+
+fn find_best_view(grid: &Grid<i8>) -> u64 {
+    grid.iter()
+        .map(|((x, y), current_tree_height)| {
+            Direction::ALL
+                .into_iter()
+                .map(|direction| count_trees(current_tree_height, grid.ray(x, y, direction)))
+                .product()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn run(grid: &Grid<i8>) -> u64 {
+    find_best_view(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_trees, find_best_view};
+    use crate::{parse_grid, tests::SAMPLE_GRID};
+
+    #[test]
+    fn test_find_best_view() {
+        let grid = parse_grid(SAMPLE_GRID).unwrap();
+        let best_view = find_best_view(&grid);
+
+        assert_eq!(8, best_view);
+    }
+
+    #[test]
+    fn test_count_trees() {
+        let trees = &[3, 3];
+        let seen_trees = count_trees(&5, trees);
+        assert_eq!(2, seen_trees);
+    }
+
+    #[test]
+    fn test_count_trees_blocked() {
+        let trees = &[3, 5, 3];
+        let seen_trees = count_trees(&5, trees);
+        assert_eq!(2, seen_trees);
+    }
+}
@@ -0,0 +1,58 @@
+use aoc_common::{parse_field, Direction, ParseError, Point, Solution};
+
+pub mod part1;
+pub mod part2;
+
+/// Where a knot ends up after the knot ahead of it has moved to `head`.
+fn follow(head: Point, tail: Point) -> Point {
+    if head.chebyshev(tail) < 2 {
+        tail
+    } else {
+        tail + (head - tail).signum()
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<(Direction, i64)>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        aoc_common::lines(input)
+            .map(|line| {
+                let (direction, steps) = line.split_once(' ').ok_or_else(|| {
+                    ParseError::new(Self::DAY, input, line, "expected a direction and steps")
+                })?;
+                let direction = match direction {
+                    "R" => Direction::Right,
+                    "L" => Direction::Left,
+                    "U" => Direction::Up,
+                    "D" => Direction::Down,
+                    _ => {
+                        return Err(ParseError::new(
+                            Self::DAY,
+                            input,
+                            direction,
+                            "expected R, L, U or D",
+                        ))
+                    }
+                };
+                Ok((direction, parse_field(Self::DAY, input, steps)?))
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> usize {
+        part1::run(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> usize {
+        part2::run(input)
+    }
+}
+
+aoc_common::sample_tests!(crate::Day9, sample => part1, part2; larger => part2);
@@ -0,0 +1,22 @@
+use std::collections::HashSet;
+
+use aoc_common::{Direction, Point};
+
+use crate::follow;
+
+pub fn run(moves: &[(Direction, i64)]) -> usize {
+    let mut visited_points: HashSet<Point> = HashSet::new();
+    let mut head = Point::ORIGIN;
+    let mut tail = head;
+    visited_points.insert(tail);
+
+    for &(direction, steps) in moves {
+        for _ in 0..steps {
+            head += direction.offset();
+            tail = follow(head, tail);
+            visited_points.insert(tail);
+        }
+    }
+
+    visited_points.len()
+}
@@ -0,0 +1,23 @@
+use std::collections::HashSet;
+
+use aoc_common::{Direction, Point};
+
+use crate::follow;
+
+pub fn run(moves: &[(Direction, i64)]) -> usize {
+    let mut visited_points: HashSet<Point> = HashSet::new();
+    let mut knots = [Point::ORIGIN; 10];
+
+    for &(direction, steps) in moves {
+        for _ in 0..steps {
+            knots[0] += direction.offset();
+            for i in 1..knots.len() {
+                knots[i] = follow(knots[i - 1], knots[i]);
+            }
+
+            visited_points.insert(knots[knots.len() - 1]);
+        }
+    }
+
+    visited_points.len()
+}